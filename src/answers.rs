@@ -0,0 +1,110 @@
+//! Known answers, to check that every day still finds them.
+//!
+//! The answers file has one answer per line, as `<day> <part> <answer>`, with
+//! line breaks in the answer (day 10's image) written as `\n`. Empty lines and
+//! lines starting with `#` are ignored.
+
+use crate::parse::{self, ParseError};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Name of the answers file, next to the inputs.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut result = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => result.push('\n'),
+            ('\\', Some('\\')) => result.push('\\'),
+            _ => {
+                result.push(c);
+                continue;
+            }
+        }
+        chars.next();
+    }
+
+    result
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(HashMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn verify(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = HashMap::new();
+
+        for line in s.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (day, rest) = parse::split_once(s, line, " ")?;
+            let (part, answer) = parse::split_once(s, rest, " ")?;
+            let day = parse::parse(s, day, "a day")?;
+            let part = parse::parse(s, part, "a part")?;
+            if answers.insert((day, part), unescape(answer)).is_some() {
+                return Err(ParseError::new(s, line, "a single answer per part"));
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_line_answers() {
+        let image = "#..#\n.##.\\";
+        let answers: Answers = format!("# Day 10\n10 2 {}\n", escape(image))
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(10, 2), Some(image));
+        assert_eq!(answers.verify(10, 2, image), Verdict::Pass);
+        assert_eq!(answers.verify(10, 2, "#..#"), Verdict::Fail);
+        assert_eq!(answers.verify(10, 1, "13140"), Verdict::Unknown);
+    }
+}
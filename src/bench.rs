@@ -0,0 +1,64 @@
+use crate::solution::{DynSolution, Result};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl FromIterator<Duration> for Timings {
+    fn from_iter<T: IntoIterator<Item = Duration>>(iter: T) -> Self {
+        let mut samples: Vec<Duration> = iter.into_iter().collect();
+        samples.sort_unstable();
+
+        Timings {
+            median: samples.get(samples.len() / 2).copied().unwrap_or_default(),
+            min: samples.first().copied().unwrap_or_default(),
+            max: samples.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub parse: Timings,
+    pub parts: Vec<(u8, Timings)>,
+}
+
+fn time<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Timings> {
+    (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f()?);
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+/// Parse `input` and solve each of the requested `parts` (that exist), each
+/// step being timed separately over a number of iterations.
+pub fn run(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[u8],
+    iterations: usize,
+) -> Result<Benchmark> {
+    let parse = time(iterations, || solution.parse(black_box(input)))?;
+    let parsed = solution.parse(input)?;
+
+    let parts = parts
+        .iter()
+        .filter(|&&part| part <= solution.parts())
+        .map(|&part| {
+            let timings = time(iterations, || solution.solve(parsed.as_ref(), part))?;
+            Ok((part, timings))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Benchmark { parse, parts })
+}
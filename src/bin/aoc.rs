@@ -0,0 +1,698 @@
+use advent_of_code_2022::answers::{self, Answers, Verdict};
+use advent_of_code_2022::bench::{self, Timings};
+use advent_of_code_2022::days::{self, Day};
+use advent_of_code_2022::generate;
+use advent_of_code_2022::input::{self, Source, STDIN};
+use advent_of_code_2022::json::Value;
+use advent_of_code_2022::parallel;
+use advent_of_code_2022::solution::DynSolution;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "Usage: aoc <DAY|all> [INPUT|-] [--part <1|2>] [--input-dir <DIR>]
+                   [--inputs <DIR>]
+                   [--bench [--iterations <N>] | --verify [--answers <FILE>]]
+                   [--format <text|json>] [--set <NAME=VALUE>]... [--parameters]
+                   [--jobs <N>] [--generate [--seed <N>] [--size <N>]]
+
+Days are solved concurrently on N threads (one per core by default), which
+also bound the threads of days that use several, and their answers printed
+in order, followed by the CPU and wall-clock time taken by each day when
+there are several of them.
+
+Inputs are read from INPUT (or the standard input for `-`), otherwise from
+dayN.txt in the --input-dir directory, $AOC_INPUT_DIR or ./inputs.
+
+With --inputs, a single day solves every file in DIR (but answers.txt)
+instead, and prints a table of the answers (and failures) for each file and
+part.
+
+With --bench, parsing and each part are timed separately over N iterations
+(10 by default) instead of printing the answers, one day at a time.
+
+With --verify, the answers are checked against those in FILE (answers.txt in
+the input directory by default), one `<day> <part> <answer>` per line.
+
+With --format json, each part is reported as a JSON object on its own line,
+with its input, answer (an array of rows for images), timings and error.
+
+With --set, a puzzle constant (such as day 15's row) is given another value,
+for every selected day that has it. --parameters lists them instead of
+solving, with their current values.
+
+With --generate, a random input for the day is printed instead, from the seed
+(the current time by default) and of the given size (what it stands for
+depends on the day, and defaults to the size of actual inputs).";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Solve,
+    Bench,
+    Verify,
+    Parameters,
+    Generate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+struct Args {
+    days: Vec<&'static Day>,
+    parts: Vec<u8>,
+    input: Option<String>,
+    input_dir: Option<PathBuf>,
+    inputs: Option<PathBuf>,
+    mode: Mode,
+    iterations: usize,
+    answers: Option<PathBuf>,
+    format: Format,
+    parameters: Vec<(String, String)>,
+    jobs: usize,
+    seed: Option<u64>,
+    size: Option<usize>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut days = None;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut input_dir = None;
+    let mut inputs = None;
+    let mut mode = Mode::Solve;
+    let mut iterations = bench::DEFAULT_ITERATIONS;
+    let mut answers = None;
+    let mut format = Format::Text;
+    let mut parameters = Vec::new();
+    let mut jobs = parallel::threads();
+    let mut seed = None;
+    let mut size = None;
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            "-p" | "--part" => {
+                let part = args.next().ok_or(USAGE)?;
+                match part.as_str() {
+                    "1" | "2" => parts = vec![part.parse().expect("Checked above.")],
+                    _ => return Err(format!("Invalid part: {part}.\n{USAGE}")),
+                }
+            }
+            "-i" | "--input" => input = Some(args.next().ok_or(USAGE)?),
+            "--input-dir" => input_dir = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "--inputs" => inputs = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "--bench" | "--verify" | "--parameters" | "--generate" if mode != Mode::Solve => {
+                return Err(format!(
+                    "Only one of --bench, --verify, --parameters and --generate.\n{USAGE}"
+                ));
+            }
+            "--bench" => mode = Mode::Bench,
+            "--verify" => mode = Mode::Verify,
+            "--parameters" => mode = Mode::Parameters,
+            "--generate" => mode = Mode::Generate,
+            "--seed" => {
+                let n = args.next().ok_or(USAGE)?;
+                seed = Some(
+                    n.parse()
+                        .map_err(|_| format!("Invalid seed: {n}.\n{USAGE}"))?,
+                );
+            }
+            "--size" => {
+                let n = args.next().ok_or(USAGE)?;
+                size = Some(
+                    n.parse()
+                        .map_err(|_| format!("Invalid size: {n}.\n{USAGE}"))?,
+                );
+            }
+            "--set" => {
+                let parameter = args.next().ok_or(USAGE)?;
+                let (name, value) = parameter
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid parameter: {parameter}.\n{USAGE}"))?;
+                parameters.push((name.to_string(), value.to_string()));
+            }
+            "--format" => {
+                let f = args.next().ok_or(USAGE)?;
+                format = match f.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("Invalid format: {f}.\n{USAGE}")),
+                };
+            }
+            "--answers" => answers = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "-n" | "--iterations" => {
+                let n = args.next().ok_or(USAGE)?;
+                iterations = match n.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid number of iterations: {n}.\n{USAGE}")),
+                };
+            }
+            "-j" | "--jobs" => {
+                let n = args.next().ok_or(USAGE)?;
+                jobs = match n.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid number of jobs: {n}.\n{USAGE}")),
+                };
+            }
+            "all" if days.is_none() => days = Some(days::DAYS.iter().collect()),
+            day if days.is_none() => {
+                let day = day
+                    .parse()
+                    .ok()
+                    .and_then(days::get)
+                    .ok_or_else(|| format!("Invalid day: {day}.\n{USAGE}"))?;
+                days = Some(vec![day]);
+            }
+            path if input.is_none() => input = Some(path.to_string()),
+            other => return Err(format!("Unexpected argument: {other}.\n{USAGE}")),
+        }
+    }
+
+    let days: Vec<&Day> = days.ok_or(USAGE)?;
+    // A single input can only belong to a single day.
+    if input.is_some() && days.len() > 1 {
+        return Err(format!(
+            "An input ({STDIN} or a path) needs a single day.\n{USAGE}"
+        ));
+    }
+
+    // Everyone's answers are different, so they can only be solved.
+    if inputs.is_some() {
+        if input.is_some() || days.len() > 1 {
+            return Err(format!(
+                "--inputs needs a single day, and no other input.\n{USAGE}"
+            ));
+        }
+        if mode != Mode::Solve {
+            return Err(format!("--inputs can only solve.\n{USAGE}"));
+        }
+    }
+
+    if mode == Mode::Generate && (input.is_some() || days.len() > 1) {
+        return Err(format!(
+            "--generate needs a single day, and no input.\n{USAGE}"
+        ));
+    }
+
+    if mode == Mode::Bench && format == Format::Json {
+        return Err(format!("--bench has no JSON format.\n{USAGE}"));
+    }
+
+    let args = Args {
+        days,
+        parts,
+        input,
+        input_dir,
+        inputs,
+        mode,
+        iterations,
+        answers,
+        format,
+        parameters,
+        jobs,
+        seed,
+        size,
+    };
+
+    // Catch typos (in names and values) before running anything.
+    for (name, _) in &args.parameters {
+        let known = args.days.iter().any(|day| {
+            let solution = day.solution();
+            solution.parameters().iter().any(|(n, _)| n == name)
+        });
+        if !known {
+            return Err(format!("Unknown parameter: {name}.\n{USAGE}"));
+        }
+    }
+    for day in &args.days {
+        solution(day, &args)?;
+    }
+
+    Ok(args)
+}
+
+// The day's solution, with the parameters it knows about set.
+fn solution(day: &Day, args: &Args) -> Result<Box<dyn DynSolution>, String> {
+    let mut solution = day.solution();
+    let names: Vec<&str> = solution.parameters().iter().map(|(n, _)| *n).collect();
+    for (name, value) in &args.parameters {
+        if names.contains(&name.as_str()) {
+            solution
+                .set(name, value)
+                .map_err(|e| format!("Day {}: {e}", day.day))?;
+        }
+    }
+
+    Ok(solution)
+}
+
+fn print_answer(part: u8, answer: &str) {
+    // Multi-line answers (such as images) start on their own line.
+    if answer.contains('\n') {
+        println!("Part {part}:\n{answer}");
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
+
+fn print_timings(day: u8, step: &str, timings: Timings) {
+    println!(
+        "{day:>3}  {step:<6}  {:>10}  {:>10}  {:>10}",
+        format!("{:.2?}", timings.median),
+        format!("{:.2?}", timings.min),
+        format!("{:.2?}", timings.max),
+    );
+}
+
+fn print_verdict(day: u8, part: u8, answer: &str, answers: &Answers) {
+    let verdict = answers.verify(day, part, answer);
+    let answer = answers::escape(answer);
+    match (verdict, answers.get(day, part)) {
+        (Verdict::Pass, _) => println!("Day {day}, part {part}: {verdict}"),
+        (Verdict::Fail, Some(expected)) => println!(
+            "Day {day}, part {part}: {verdict} (expected {}, found {answer})",
+            answers::escape(expected)
+        ),
+        _ => println!("Day {day}, part {part}: {verdict} (found {answer})"),
+    }
+}
+
+fn source(day: &Day, args: &Args) -> Source {
+    Source::resolve(day.day, args.input.as_deref(), args.input_dir.as_deref())
+}
+
+fn read_input(source: &Source) -> Result<String, String> {
+    source
+        .read()
+        .map_err(|e| format!("cannot read {source}: {e}"))
+}
+
+#[derive(Debug)]
+struct PartReport {
+    part: u8,
+    time: Option<Duration>,
+    answer: Result<String, String>,
+}
+
+// Everything about running a day, so that it can be reported in any format.
+#[derive(Debug)]
+struct Report {
+    day: u8,
+    source: Source,
+    parse_time: Option<Duration>,
+    // CPU time spent parsing and solving, on every thread the day used, where
+    // it can be measured.
+    cpu_time: Option<Duration>,
+    // Reading or parsing the input failed, so every part failed with it.
+    error: Option<String>,
+    parts: Vec<PartReport>,
+}
+
+impl Report {
+    fn run(day: &Day, args: &Args, source: Source) -> Self {
+        let solution = solution(day, args).expect("Checked with the arguments.");
+        let parts: Vec<u8> = args
+            .parts
+            .iter()
+            .copied()
+            .filter(|&part| part <= solution.parts())
+            .collect();
+        let failure = |parse_time, cpu_time, error: String| Report {
+            day: day.day,
+            source: source.clone(),
+            parse_time,
+            cpu_time,
+            parts: parts
+                .iter()
+                .map(|&part| PartReport {
+                    part,
+                    time: None,
+                    answer: Err(error.clone()),
+                })
+                .collect(),
+            error: Some(error),
+        };
+
+        let input = match read_input(&source) {
+            Ok(input) => input,
+            Err(e) => return failure(None, None, e),
+        };
+
+        let cpu_start = parallel::cpu_time();
+        let cpu_time = || parallel::cpu_time()?.checked_sub(cpu_start?);
+        let start = Instant::now();
+        let input = solution.parse(&input);
+        let parse_time = start.elapsed();
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => return failure(Some(parse_time), cpu_time(), e.to_string()),
+        };
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = solution
+                    .solve(input.as_ref(), part)
+                    .map_err(|e| e.to_string());
+                PartReport {
+                    part,
+                    time: Some(start.elapsed()),
+                    answer,
+                }
+            })
+            .collect();
+
+        Report {
+            day: day.day,
+            source,
+            parse_time: Some(parse_time),
+            cpu_time: cpu_time(),
+            error: None,
+            parts,
+        }
+    }
+
+    // Wall-clock time spent parsing and solving, longer than needed when days
+    // compete for cores.
+    fn time(&self) -> Duration {
+        self.parse_time.unwrap_or_default()
+            + self
+                .parts
+                .iter()
+                .filter_map(|part| part.time)
+                .sum::<Duration>()
+    }
+
+    // Whether anything went wrong, including wrong answers when they are
+    // verified.
+    fn failed(&self, answers: Option<&Answers>) -> bool {
+        self.parts.iter().any(|part| match &part.answer {
+            Ok(answer) => answers.is_some_and(|answers| {
+                answers.verify(self.day, part.part, answer) == Verdict::Fail
+            }),
+            Err(_) => true,
+        }) || self.error.is_some()
+    }
+
+    fn print_text(&self, answers: Option<&Answers>) {
+        if let Some(e) = &self.error {
+            eprintln!("Day {}: {e}", self.day);
+            return;
+        }
+
+        for part in &self.parts {
+            match (&part.answer, answers) {
+                (Ok(answer), Some(answers)) => print_verdict(self.day, part.part, answer, answers),
+                (Ok(answer), None) => print_answer(part.part, answer),
+                (Err(e), _) => eprintln!("Day {}, part {}: {e}", self.day, part.part),
+            }
+        }
+    }
+
+    // One object per line, and per part.
+    fn print_json(&self, answers: Option<&Answers>) {
+        let seconds = |time: Option<Duration>| Value::from(time.map(|time| time.as_secs_f64()));
+
+        for part in &self.parts {
+            let (answer, error) = match &part.answer {
+                // Multi-line answers (such as images) are split into rows.
+                Ok(answer) if answer.contains('\n') => (
+                    Value::Array(answer.lines().map(Value::from).collect()),
+                    Value::Null,
+                ),
+                Ok(answer) => (Value::from(answer.as_str()), Value::Null),
+                Err(e) => (Value::Null, Value::from(e.as_str())),
+            };
+
+            let mut fields = vec![
+                ("day", Value::from(self.day)),
+                ("input", Value::from(self.source.to_string())),
+                ("part", Value::from(part.part)),
+                ("answer", answer),
+                ("parse_seconds", seconds(self.parse_time)),
+                ("solve_seconds", seconds(part.time)),
+                ("error", error),
+            ];
+            if let Some(answers) = answers {
+                let verdict = part
+                    .answer
+                    .as_ref()
+                    .ok()
+                    .map(|answer| answers.verify(self.day, part.part, answer).to_string());
+                fields.push(("verdict", Value::from(verdict)));
+            }
+
+            println!("{}", Value::object(fields));
+        }
+    }
+}
+
+// Returns whether the day ran without errors.
+fn benchmark(day: &Day, args: &Args) -> bool {
+    let result = read_input(&source(day, args)).and_then(|input| {
+        let solution = solution(day, args)?;
+        bench::run(solution.as_ref(), &input, &args.parts, args.iterations)
+            .map_err(|e| e.to_string())
+    });
+
+    match result {
+        Ok(benchmark) => {
+            print_timings(day.day, "parse", benchmark.parse);
+            for (part, timings) in benchmark.parts {
+                print_timings(day.day, &format!("part {part}"), timings);
+            }
+            true
+        }
+        Err(e) => {
+            eprintln!("Day {}: {e}", day.day);
+            false
+        }
+    }
+}
+
+// One row per file and part, with columns as wide as their contents. Errors
+// follow the table, so as not to make it too wide.
+fn print_table(files: &[PathBuf], reports: &[Report]) {
+    let mut rows = vec![["Input", "Part", "Answer", "Time"].map(String::from)];
+    let mut errors = Vec::new();
+    for (file, report) in files.iter().zip(reports) {
+        let name = file.file_name().map_or_else(
+            || file.display().to_string(),
+            |name| name.to_string_lossy().into(),
+        );
+        if let Some(e) = &report.error {
+            errors.push(format!("{name}: {e}"));
+            rows.push([name, "-".into(), "ERROR".into(), "-".into()]);
+            continue;
+        }
+
+        for part in &report.parts {
+            let answer = match &part.answer {
+                Ok(answer) => answers::escape(answer),
+                Err(e) => {
+                    errors.push(format!("{name}, part {}: {e}", part.part));
+                    "ERROR".into()
+                }
+            };
+            let time = part.time.map_or("-".into(), |time| format!("{time:.2?}"));
+            rows.push([name.clone(), part.part.to_string(), answer, time]);
+        }
+    }
+
+    let widths = (0..4).map(|col| rows.iter().map(|row| row[col].chars().count()).max());
+    let widths: Vec<usize> = widths.map(Option::unwrap_or_default).collect();
+    for [name, part, answer, time] in &rows {
+        println!(
+            "{name:<w0$}  {part:<w1$}  {answer:<w2$}  {time:>w3$}",
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+    for e in errors {
+        eprintln!("{e}");
+    }
+}
+
+// Returns whether every file was solved.
+fn batch(day: &Day, dir: &Path, args: &Args) -> bool {
+    let files = match input::files(dir) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("Cannot read {}: {e}", dir.display());
+            return false;
+        }
+    };
+
+    let reports = parallel::map(&files, args.jobs, |file| {
+        Report::run(day, args, Source::File(file.clone()))
+    });
+    match args.format {
+        Format::Text => print_table(&files, &reports),
+        Format::Json => {
+            for report in &reports {
+                report.print_json(None);
+            }
+        }
+    }
+
+    !reports.iter().any(|report| report.failed(None))
+}
+
+fn print_parameters(day: &Day, args: &Args) {
+    let solution = solution(day, args).expect("Checked with the arguments.");
+    let parameters = solution.parameters();
+    match args.format {
+        Format::Text if parameters.is_empty() => println!("Day {}: no parameters", day.day),
+        Format::Text => {
+            println!("Day {}", day.day);
+            for (name, value) in parameters {
+                println!("  {name} = {value}");
+            }
+        }
+        Format::Json => {
+            let parameters = parameters
+                .into_iter()
+                .map(|(name, value)| (name, Value::from(value)));
+            println!(
+                "{}",
+                Value::object([
+                    ("day", Value::from(day.day)),
+                    ("parameters", Value::object(parameters)),
+                ])
+            );
+        }
+    }
+}
+
+fn print_summary(reports: &[Report], wall_clock: Duration) {
+    // Days whose input could not be read took no time at all.
+    let format = |time: Option<Duration>| time.map_or("-".to_string(), |t| format!("{t:.2?}"));
+    println!();
+    println!("Day  {:>10}  {:>10}", "CPU time", "Wall time");
+    for report in reports {
+        let wall_time = report.parse_time.map(|_| report.time());
+        println!(
+            "{:>3}  {:>10}  {:>10}",
+            report.day,
+            format(report.cpu_time),
+            format(wall_time)
+        );
+    }
+    let cpu_times: Option<Vec<Duration>> = reports
+        .iter()
+        .filter(|report| report.parse_time.is_some())
+        .map(|report| report.cpu_time)
+        .collect();
+    println!(
+        "Total: {} CPU time, {wall_clock:.2?} wall-clock",
+        format(cpu_times.map(|times| times.into_iter().sum()))
+    );
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+    };
+
+    let answers = if args.mode == Mode::Verify {
+        let path = args.answers.clone().unwrap_or_else(|| {
+            input::directory(args.input_dir.as_deref()).join(answers::ANSWERS_FILE)
+        });
+        let answers = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Cannot read {}: {e}", path.display()))
+            .and_then(|answers| {
+                answers
+                    .parse::<Answers>()
+                    .map_err(|e| format!("{}: {e}", path.display()))
+            });
+        match answers {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(2);
+            }
+        }
+    } else {
+        None
+    };
+
+    // Keep going when a day fails, so that a single bad input does not hide
+    // the results of all the others.
+    let mut failed = false;
+    match args.mode {
+        Mode::Generate => {
+            let generator = generate::get(args.days[0].day).expect("Every day has a generator.");
+            // Without a seed, report the one used so that the input can be
+            // generated again.
+            let seed = args.seed.unwrap_or_else(|| {
+                let seed = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |time| time.as_nanos() as u64);
+                eprintln!("Seed: {seed}");
+                seed
+            });
+            print!(
+                "{}",
+                generator.generate(seed, args.size.unwrap_or(generator.default_size))
+            );
+        }
+        Mode::Parameters => {
+            for day in &args.days {
+                print_parameters(day, &args);
+            }
+        }
+        // Timings would suffer from running days concurrently.
+        Mode::Bench => {
+            println!(
+                "Day  Step    {:>10}  {:>10}  {:>10}",
+                "Median", "Min", "Max"
+            );
+            parallel::limit(args.jobs, || {
+                for day in &args.days {
+                    failed |= !benchmark(day, &args);
+                }
+            });
+        }
+        Mode::Solve if args.inputs.is_some() => {
+            let dir = args.inputs.as_deref().expect("Checked above.");
+            failed |= !batch(args.days[0], dir, &args);
+        }
+        Mode::Solve | Mode::Verify => {
+            let start = Instant::now();
+            let reports = parallel::map(&args.days, args.jobs, |day| {
+                Report::run(day, &args, source(day, &args))
+            });
+            let wall_clock = start.elapsed();
+
+            for report in &reports {
+                if reports.len() > 1 && args.mode == Mode::Solve && args.format == Format::Text {
+                    println!("Day {}", report.day);
+                }
+                match args.format {
+                    Format::Text => report.print_text(answers.as_ref()),
+                    Format::Json => report.print_json(answers.as_ref()),
+                }
+                failed |= report.failed(answers.as_ref());
+            }
+
+            if reports.len() > 1 && args.format == Format::Text {
+                print_summary(&reports, wall_clock);
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
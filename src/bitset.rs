@@ -0,0 +1,330 @@
+//! Sets of small integers, one bit each, cheap to copy and to use as keys.
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+/// A set of integers below `64 * W`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitSet<const W: usize = 1>([u64; W]);
+
+impl<const W: usize> BitSet<W> {
+    /// How many integers can be in the set: all of them below this one.
+    pub const CAPACITY: usize = 64 * W;
+
+    pub const fn new() -> Self {
+        BitSet([0; W])
+    }
+
+    /// Whether `value` was not in the set yet.
+    pub fn insert(&mut self, value: usize) -> bool {
+        assert!(value < Self::CAPACITY, "{value} does not fit in the set.");
+        let (word, bit) = (value / 64, 1 << (value % 64));
+        let added = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        added
+    }
+
+    /// Whether `value` was in the set.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let removed = self.contains(value);
+        if removed {
+            self.0[value / 64] &= !(1 << (value % 64));
+        }
+        removed
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        value < Self::CAPACITY && self.0[value / 64] & (1 << (value % 64)) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        self | other
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        self & other
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        self - other
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0.iter().zip(other.0).all(|(a, b)| a & b == 0)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.iter().zip(other.0).all(|(a, b)| a & !b == 0)
+    }
+
+    /// The values in the set, smallest first.
+    pub fn iter(&self) -> Iter<W> {
+        Iter {
+            words: self.0,
+            word: 0,
+        }
+    }
+}
+
+impl<const W: usize> Default for BitSet<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize> Debug for BitSet<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const W: usize> BitOr for BitSet<W> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl<const W: usize> BitOrAssign for BitSet<W> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a |= b);
+    }
+}
+
+impl<const W: usize> BitAnd for BitSet<W> {
+    type Output = Self;
+
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+
+impl<const W: usize> BitAndAssign for BitSet<W> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a &= b);
+    }
+}
+
+impl<const W: usize> Sub for BitSet<W> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const W: usize> SubAssign for BitSet<W> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a &= !b);
+    }
+}
+
+impl<const W: usize> Extend<usize> for BitSet<W> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<const W: usize> FromIterator<usize> for BitSet<W> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const W: usize> IntoIterator for BitSet<W> {
+    type Item = usize;
+    type IntoIter = Iter<W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The values of a `BitSet`, smallest first.
+#[derive(Debug, Clone)]
+pub struct Iter<const W: usize> {
+    // What is left to go through, from the `word`th word on.
+    words: [u64; W],
+    word: usize,
+}
+
+impl<const W: usize> Iterator for Iter<W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word < W {
+            let bits = &mut self.words[self.word];
+            if *bits != 0 {
+                let bit = bits.trailing_zeros() as usize;
+                // Clear the lowest bit.
+                *bits &= *bits - 1;
+                return Some(self.word * 64 + bit);
+            }
+            self.word += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.words[self.word.min(W)..]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+        (len, Some(len))
+    }
+}
+
+impl<const W: usize> ExactSizeIterator for Iter<W> {}
+
+/// Values numbered in the order they first came in, so that sets of them can
+/// be `BitSet`s of their numbers.
+#[derive(Debug, Clone)]
+pub struct Universe<T> {
+    indices: HashMap<T, usize>,
+    values: Vec<T>,
+}
+
+impl<T: Clone + Eq + Hash> Universe<T> {
+    pub fn new() -> Self {
+        Universe {
+            indices: HashMap::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The number of `value`, given the next one if it is new.
+    pub fn insert(&mut self, value: T) -> usize {
+        let next = self.values.len();
+        *self.indices.entry(value.clone()).or_insert_with(|| {
+            self.values.push(value);
+            next
+        })
+    }
+
+    pub fn index(&self, value: &T) -> Option<usize> {
+        self.indices.get(value).copied()
+    }
+
+    pub fn value(&self, index: usize) -> &T {
+        &self.values[index]
+    }
+
+    /// The numbers of `values`, if they are all known.
+    pub fn set<'a, const W: usize>(
+        &self,
+        values: impl IntoIterator<Item = &'a T>,
+    ) -> Option<BitSet<W>>
+    where
+        T: 'a,
+    {
+        values.into_iter().map(|value| self.index(value)).collect()
+    }
+
+    /// The values numbered in `set`.
+    pub fn values<const W: usize>(&self, set: BitSet<W>) -> impl Iterator<Item = &T> {
+        set.iter().map(|index| self.value(index))
+    }
+}
+
+impl<T: Clone + Eq + Hash> Default for Universe<T> {
+    fn default() -> Self {
+        Universe::new()
+    }
+}
+
+impl<T: Clone + Eq + Hash> FromIterator<T> for Universe<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut universe = Universe::new();
+        for value in iter {
+            universe.insert(value);
+        }
+        universe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use std::collections::BTreeSet;
+
+    fn random_set(rng: &mut Rng) -> (BitSet<2>, BTreeSet<usize>) {
+        let values: BTreeSet<usize> = (0..rng.range(0..=20)).map(|_| rng.range(0..=127)).collect();
+        (values.iter().copied().collect(), values)
+    }
+
+    #[test]
+    fn sets_match_values() {
+        let mut rng = Rng::new(25);
+        for _ in 0..500 {
+            let (a, a_values) = random_set(&mut rng);
+            let (b, b_values) = random_set(&mut rng);
+
+            assert_eq!(a.iter().collect::<BTreeSet<_>>(), a_values);
+            assert_eq!(a.len(), a_values.len());
+            assert_eq!(a.iter().len(), a_values.len());
+            assert!((0..130).all(|x| a.contains(x) == a_values.contains(&x)));
+
+            let values = |set: BitSet<2>| set.iter().collect::<BTreeSet<_>>();
+            assert_eq!(values(a | b), &a_values | &b_values);
+            assert_eq!(values(a & b), &a_values & &b_values);
+            assert_eq!(values(a - b), &a_values - &b_values);
+            assert_eq!(a.is_disjoint(&b), a_values.is_disjoint(&b_values));
+            assert_eq!(a.is_subset(&b), a_values.is_subset(&b_values));
+            assert!((a & b).is_subset(&a));
+        }
+    }
+
+    #[test]
+    fn changes() {
+        let mut set = BitSet::<1>::new();
+        assert!(set.is_empty());
+        assert!(set.insert(63));
+        assert!(!set.insert(63));
+        assert!(set.insert(0));
+        assert_eq!(format!("{set:?}"), "{0, 63}");
+        assert!(set.remove(0));
+        assert!(!set.remove(0));
+        assert!(!set.remove(64));
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), [63]);
+    }
+
+    #[test]
+    fn universe() {
+        let mut universe: Universe<&str> = ["AA", "BB", "AA", "CC"].into_iter().collect();
+        assert_eq!(universe.len(), 3);
+        assert_eq!(universe.insert("BB"), 1);
+        assert_eq!(universe.insert("DD"), 3);
+
+        let set: BitSet = universe.set(&["CC", "AA"]).unwrap();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(universe.values(set).collect::<Vec<_>>(), [&"AA", &"CC"]);
+        assert_eq!(universe.set::<1>(&["EE"]), None);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::parse;
+use crate::solution::{self, Solution};
+use std::cmp::Reverse;
+
+type Calories = u64;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Calories>;
+    type Part1 = Calories;
+    type Part2 = Calories;
+
+    const DAY: u8 = 1;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        let mut calories = input
+            .split("\n\n")
+            .map(|elf| {
+                elf.lines()
+                    .map(|line| parse::parse::<Calories>(input, line, "a number of calories"))
+                    .sum()
+            })
+            .collect::<Result<Vec<Calories>, _>>()?;
+        calories.sort_unstable_by_key(|calories| Reverse(*calories));
+
+        Ok(calories)
+    }
+
+    fn part1(&self, calories: &Self::Input) -> solution::Result<Self::Part1> {
+        // Find the Elf carrying the most Calories. How many total Calories is
+        // that Elf carrying?
+        Ok(calories.first().copied().unwrap_or_default())
+    }
+
+    fn part2(&self, calories: &Self::Input) -> solution::Result<Self::Part2> {
+        // Find the top three Elves carrying the most Calories. How many
+        // Calories are those Elves carrying in total?
+        Ok(calories.iter().take(3).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn example() {
+        let calories = Day1.parse(EXAMPLE).unwrap();
+        assert_eq!(calories, [24000, 11000, 10000, 6000, 4000]);
+        assert_eq!(Day1.part1(&calories).unwrap(), 24000);
+        assert_eq!(Day1.part2(&calories).unwrap(), 45000);
+    }
+}
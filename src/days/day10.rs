@@ -0,0 +1,289 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+use std::str::FromStr;
+
+type Value = i64;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(Value),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut iter = s.split_whitespace();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some("noop"), None, None) => Ok(Instruction::Noop),
+            (Some("addx"), Some(value), None) => {
+                Ok(Instruction::Addx(parse::parse(s, value, "a value")?))
+            }
+            _ => Err(ParseError::new(s, s, "noop or addx <value>")),
+        }
+    }
+}
+
+fn register(instructions: &[Instruction]) -> impl Iterator<Item = i64> + '_ {
+    std::iter::once(0)
+        .chain(instructions.iter().flat_map(|instruction| {
+            std::iter::once(0_i64).chain(match instruction {
+                Instruction::Noop => None,
+                Instruction::Addx(value) => Some(*value),
+            })
+        }))
+        .scan(1_i64, |current, value| {
+            *current += value;
+            Some(*current)
+        })
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = String;
+
+    const DAY: u8 = 10;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(parse::lines(input).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> solution::Result<Self::Part1> {
+        // Find the signal strength during the 20th, 60th, 100th, 140th, 180th,
+        // and 220th cycles. What is the sum of these six signal strengths?
+        const CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+        Ok(register(instructions)
+            .enumerate()
+            .filter_map(|(cycle, value)| {
+                // Offset by 1, since we want the values at the end of the
+                // cycles.
+                let cycle = cycle + 1;
+                if CYCLES.contains(&cycle) {
+                    Some(value * cycle as i64)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> solution::Result<Self::Part2> {
+        // Render the image given by your program. What eight capital letters
+        // appear on your CRT?
+        const HEIGHT: i64 = 6;
+        const WIDTH: i64 = 40;
+        let mut iter = register(instructions);
+
+        let lines: Vec<String> = (0..HEIGHT)
+            .map(|_| {
+                (0..WIDTH)
+                    .zip(iter.by_ref())
+                    .map(|(h, v)| if (h - v).abs() <= 1 { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+noop
+addx 3
+addx -5
+";
+
+    const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+";
+
+    #[test]
+    fn register_trace() {
+        // The value during each cycle, then after the last one.
+        let instructions = Day10.parse(SMALL_EXAMPLE).unwrap();
+        let trace: Vec<Value> = register(&instructions).collect();
+        assert_eq!(trace, [1, 1, 1, 4, 4, -1]);
+
+        let instructions = Day10.parse(EXAMPLE).unwrap();
+        let trace: Vec<Value> = register(&instructions).collect();
+        for (cycle, value) in [
+            (20, 21),
+            (60, 19),
+            (100, 18),
+            (140, 21),
+            (180, 16),
+            (220, 18),
+        ] {
+            assert_eq!(trace[cycle - 1], value, "cycle {cycle}");
+        }
+    }
+
+    #[test]
+    fn example() {
+        let instructions = Day10.parse(EXAMPLE).unwrap();
+        assert_eq!(Day10.part1(&instructions).unwrap(), 13140);
+        assert_eq!(
+            Day10.part2(&instructions).unwrap(),
+            "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+}
@@ -0,0 +1,355 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+use std::num::NonZeroU64;
+use std::str::FromStr;
+
+type WorryLevel = u64;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add(WorryLevel),
+    Mul(WorryLevel),
+    AddSelf,
+    MulSelf,
+}
+
+impl Op {
+    fn apply(self, worry_level: WorryLevel) -> Option<WorryLevel> {
+        match self {
+            Op::Add(w) => worry_level.checked_add(w),
+            Op::Mul(w) => worry_level.checked_mul(w),
+            Op::AddSelf => worry_level.checked_mul(2),
+            Op::MulSelf => worry_level.checked_mul(worry_level),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Test {
+    value: WorryLevel,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Test {
+    fn apply(self, worry_level: WorryLevel) -> usize {
+        if worry_level.is_multiple_of(self.value) {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<WorryLevel>,
+    op: Op,
+    test: Test,
+    items_inspected: usize,
+}
+
+impl Monkey {
+    fn throws(&mut self, relief: NonZeroU64) -> solution::Result<Vec<(usize, WorryLevel)>> {
+        self.items_inspected += self.items.len();
+
+        let result = self
+            .items
+            .iter()
+            .map(|item| {
+                let w = self.op.apply(*item).ok_or("Worry level too large.")? / relief;
+                let d = self.test.apply(w);
+                Ok((d, w))
+            })
+            .collect();
+        self.items.clear();
+
+        result
+    }
+
+    fn catch(&mut self, item: WorryLevel) {
+        self.items.push(item);
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        // The rest of the next line, which must start with `prefix`.
+        let mut field = |prefix: &str| {
+            let line = lines.next().unwrap_or(&s[s.len()..]);
+            parse::strip_prefix(s, line.trim_start(), prefix)
+        };
+
+        // Skip the monkey id.
+        field("Monkey ")?;
+
+        // Items.
+        let items = field("Starting items: ")?
+            .split(", ")
+            .filter(|item| !item.is_empty())
+            .map(|item| parse::parse(s, item, "a worry level"))
+            .collect::<Result<_, _>>()?;
+
+        // Worry level operation.
+        let (operator, operand) = parse::split_once(s, field("Operation: new = old ")?, " ")?;
+        let op = match (operator, operand) {
+            ("+", "old") => Op::AddSelf,
+            ("*", "old") => Op::MulSelf,
+            ("+", v) => Op::Add(parse::parse(s, v, "a worry level or old")?),
+            ("*", v) => Op::Mul(parse::parse(s, v, "a worry level or old")?),
+            _ => return Err(ParseError::new(s, operator, "+ or *")),
+        };
+
+        // Test.
+        let test_value: NonZeroU64 =
+            parse::parse(s, field("Test: divisible by ")?, "a (non-zero) divisor")?;
+        let if_true = parse::parse(s, field("If true: throw to monkey ")?, "a monkey")?;
+        let if_false = parse::parse(s, field("If false: throw to monkey ")?, "a monkey")?;
+
+        let test = Test {
+            value: test_value.get(),
+            if_true,
+            if_false,
+        };
+
+        Ok(Monkey {
+            items,
+            op,
+            test,
+            items_inspected: 0,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeepAwayGame {
+    monkeys: Vec<Monkey>,
+    modulus: WorryLevel,
+}
+
+impl KeepAwayGame {
+    fn round(&mut self, relief: NonZeroU64) -> solution::Result<&mut Self> {
+        for idx in 0..self.monkeys.len() {
+            let throws = self.monkeys[idx].throws(relief)?;
+            for (idx, item) in throws {
+                // Dividing by the relief does not commute with taking the
+                // remainder, so with relief worry levels are kept whole.
+                let item = if relief == NonZeroU64::MIN {
+                    item % self.modulus
+                } else {
+                    item
+                };
+                self.monkeys[idx].catch(item);
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn monkey_business_level(&self) -> usize {
+        let mut inspections: Vec<usize> = self.monkeys.iter().map(|m| m.items_inspected).collect();
+        inspections.sort_unstable_by_key(|i| std::cmp::Reverse(*i));
+
+        inspections.into_iter().take(2).product()
+    }
+}
+
+impl FromStr for KeepAwayGame {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let blocks: Vec<&str> = s.split("\n\n").collect();
+        let monkeys = blocks
+            .iter()
+            .map(|block| block.parse().map_err(|e: ParseError| e.within(s, block)))
+            .collect::<Result<Vec<Monkey>, _>>()?;
+
+        // Monkeys can only throw to monkeys that exist.
+        if let Some((block, _)) = blocks
+            .iter()
+            .zip(&monkeys)
+            .find(|(_, monkey)| monkey.test.if_true.max(monkey.test.if_false) >= monkeys.len())
+        {
+            return Err(ParseError::new(
+                s,
+                block,
+                format!("monkeys to throw to between 0 and {}", monkeys.len() - 1),
+            ));
+        }
+
+        // We will quickly overflow when we can't get no relief. Looking at the
+        // tests, we are dealing with prime numbers only, so we can use their
+        // product to keep worry levels manageable.
+        let modulus = monkeys.iter().map(|monkey| monkey.test.value).product();
+        Ok(KeepAwayGame { monkeys, modulus })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day11 {
+    part1_rounds: usize,
+    part2_rounds: usize,
+    part1_relief: NonZeroU64,
+    part2_relief: NonZeroU64,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            part1_rounds: 20,
+            part2_rounds: 10000,
+            part1_relief: NonZeroU64::new(3).expect("Not zero."),
+            part2_relief: NonZeroU64::MIN,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = KeepAwayGame;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 11;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, game: &Self::Input) -> solution::Result<Self::Part1> {
+        // Figure out which monkeys to chase by counting how many items they
+        // inspect over 20 rounds. What is the level of monkey business after
+        // 20 rounds of stuff-slinging simian shenanigans?
+        let mut game = game.clone();
+        for _ in 0..self.part1_rounds {
+            game.round(self.part1_relief)?;
+        }
+        Ok(game.monkey_business_level())
+    }
+
+    fn part2(&self, game: &Self::Input) -> solution::Result<Self::Part2> {
+        // Worry levels are no longer divided by three after each item is
+        // inspected; you'll need to find another way to keep your worry levels
+        // manageable. Starting again from the initial state in your puzzle
+        // input, what is the level of monkey business after 10000 rounds?
+        let mut game = game.clone();
+        for _ in 0..self.part2_rounds {
+            game.round(self.part2_relief)?;
+        }
+        Ok(game.monkey_business_level())
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("part1_rounds", self.part1_rounds.to_string()),
+            ("part2_rounds", self.part2_rounds.to_string()),
+            ("part1_relief", self.part1_relief.to_string()),
+            ("part2_relief", self.part2_relief.to_string()),
+        ]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> solution::Result<()> {
+        match name {
+            "part1_rounds" => self.part1_rounds = solution::parameter(name, value)?,
+            "part2_rounds" => self.part2_rounds = solution::parameter(name, value)?,
+            "part1_relief" => self.part1_relief = solution::parameter(name, value)?,
+            "part2_relief" => self.part2_relief = solution::parameter(name, value)?,
+            _ => return Err(solution::unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn rounds() {
+        let relief = Day11::default().part1_relief;
+        let mut game = Day11::default().parse(EXAMPLE).unwrap();
+        game.round(relief).unwrap();
+        let items: Vec<&[WorryLevel]> = game.monkeys.iter().map(|m| m.items.as_slice()).collect();
+        assert_eq!(
+            items,
+            [
+                &[20, 23, 27, 26][..],
+                &[2080, 25, 167, 207, 401, 1046],
+                &[],
+                &[]
+            ]
+        );
+
+        for _ in 1..20 {
+            game.round(relief).unwrap();
+        }
+        let inspected: Vec<usize> = game.monkeys.iter().map(|m| m.items_inspected).collect();
+        assert_eq!(inspected, [101, 95, 7, 105]);
+    }
+
+    #[test]
+    fn rounds_without_relief() {
+        let relief = Day11::default().part2_relief;
+        let mut game = Day11::default().parse(EXAMPLE).unwrap();
+        game.round(relief).unwrap();
+        let inspected: Vec<usize> = game.monkeys.iter().map(|m| m.items_inspected).collect();
+        assert_eq!(inspected, [2, 4, 3, 6]);
+
+        for _ in 1..20 {
+            game.round(relief).unwrap();
+        }
+        let inspected: Vec<usize> = game.monkeys.iter().map(|m| m.items_inspected).collect();
+        assert_eq!(inspected, [99, 97, 8, 103]);
+    }
+
+    #[test]
+    fn example() {
+        let day = Day11::default();
+        let game = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&game).unwrap(), 10605);
+        assert_eq!(day.part2(&game).unwrap(), 2713310158);
+    }
+
+    #[test]
+    fn relief_with_many_rounds() {
+        let mut day = Day11::default();
+        day.set("part2_relief", "3").unwrap();
+        let game = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part2(&game).unwrap_err().to_string(), "Worry level too large.");
+
+        day.set("part2_rounds", "20").unwrap();
+        assert_eq!(day.part2(&game).unwrap(), 10605);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::search;
+use crate::solution::{self, Solution};
+
+type Map = Grid<u8>;
+
+fn parse_input(input: &str) -> Result<(Position, Position, Map), ParseError> {
+    let (mut starts, mut ends) = (0, 0);
+    let squares = Grid::parse_with(input, None, |c| {
+        match c {
+            'S' => starts += 1,
+            'E' => ends += 1,
+            _ => (),
+        }
+        match c {
+            'S' if starts == 1 => Ok(c),
+            'E' if ends == 1 => Ok(c),
+            'a'..='z' => Ok(c),
+            _ => Err("an elevation (a to z), or a single S and E"),
+        }
+    })?;
+
+    let find = |square, expected| {
+        squares
+            .iter()
+            .find(|&(_, &c)| c == square)
+            .map(|(position, _)| position)
+            .ok_or_else(|| ParseError::new(input, &input[input.len()..], expected))
+    };
+    Ok((
+        find('S', "a start (S)")?,
+        find('E', "an end (E)")?,
+        squares.map(|&c| match c {
+            'S' => 0,
+            'E' => 25,
+            _ => c as u8 - b'a',
+        }),
+    ))
+}
+
+// Where we can go from `position`, climbing at most one step.
+fn climbs(grid: &Map, position: Position) -> impl Iterator<Item = Position> + '_ {
+    grid.neighbours4(position)
+        .filter(move |&next| grid[position] + 1 >= grid[next])
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = (Position, Position, Map);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 12;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, (start, end, grid): &Self::Input) -> solution::Result<Self::Part1> {
+        // What is the fewest steps required to move from your current position
+        // to the location that should get the best signal?
+        search::bfs([*start], |&p| climbs(grid, p), |&p| p == *end)
+            .map(|path| path.cost)
+            .ok_or_else(|| "No path to the end.".into())
+    }
+
+    fn part2(&self, (_, end, grid): &Self::Input) -> solution::Result<Self::Part2> {
+        // What is the fewest steps required to move starting from any square
+        // with elevation a to the location that should get the best signal?
+        let starts = grid
+            .iter()
+            .filter(|&(_, &height)| height == 0)
+            .map(|(p, _)| p);
+        search::bfs(starts, |&p| climbs(grid, p), |&p| p == *end)
+            .map(|path| path.cost)
+            .ok_or_else(|| "No path from elevation a.".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+    #[test]
+    fn example() {
+        let input = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(input.0, Position::new(0, 0));
+        assert_eq!(input.1, Position::new(5, 2));
+        assert_eq!(Day12.part1(&input).unwrap(), 31);
+        assert_eq!(Day12.part2(&input).unwrap(), 29);
+    }
+}
@@ -0,0 +1,199 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+type Value = u8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Integer(Value),
+    List(Vec<Packet>),
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+            (Packet::Integer(left), Packet::List(right)) => {
+                [Packet::Integer(*left)].as_slice().cmp(right)
+            }
+            (Packet::List(left), Packet::Integer(right)) => {
+                left.as_slice().cmp(&[Packet::Integer(*right)])
+            }
+        }
+    }
+}
+
+// Parse a list from right after its opening bracket, returning the rest of
+// the text after its closing bracket as well.
+fn parse_list<'a>(source: &str, mut text: &'a str) -> Result<(Packet, &'a str), ParseError> {
+    let mut values = Vec::new();
+    loop {
+        match text.bytes().next() {
+            Some(b']') => return Ok((Packet::List(values), &text[1..])),
+            Some(b'[') => {
+                let (list, rest) = parse_list(source, &text[1..])?;
+                values.push(list);
+                text = rest;
+            }
+            Some(b'0'..=b'9') => {
+                let end = text
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(text.len());
+                values.push(Packet::Integer(parse::parse(
+                    source,
+                    &text[..end],
+                    "an integer",
+                )?));
+                text = &text[end..];
+            }
+            _ => return Err(ParseError::new(source, text, "an integer, a list or ]")),
+        }
+
+        match text.bytes().next() {
+            Some(b',') => text = &text[1..],
+            Some(b']') => (),
+            _ => return Err(ParseError::new(source, text, ", or ]")),
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (packet, rest) = parse_list(s, parse::strip_prefix(s, s, "[")?)?;
+        if rest.is_empty() {
+            Ok(packet)
+        } else {
+            Err(ParseError::new(s, rest, "the end of the packet"))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 13;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input
+            .split("\n\n")
+            .map(|pair| {
+                let mut iter = pair.lines();
+                let mut packet = || {
+                    let line = iter.next().unwrap_or(&pair[pair.len()..]);
+                    line.parse::<Packet>().map_err(|e| e.within(input, line))
+                };
+                let pair = (packet()?, packet()?);
+                match iter.next() {
+                    Some(line) => Err(ParseError::new(input, line, "a blank line")),
+                    None => Ok(pair),
+                }
+            })
+            .collect::<Result<_, ParseError>>()?)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> solution::Result<Self::Part1> {
+        // Determine which pairs of packets are already in the right order.
+        // What is the sum of the indices of those pairs?
+        Ok(pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, (left, right))| if left <= right { Some(idx + 1) } else { None })
+            .sum())
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> solution::Result<Self::Part2> {
+        // Organize all of the packets into the correct order.
+        // What is the decoder key for the distress signal?
+        let first_divider: Packet = "[[2]]".parse().expect("Infallible.");
+        let second_divider: Packet = "[[6]]".parse().expect("Infallible.");
+
+        let mut packets: Vec<Packet> = pairs
+            .iter()
+            .flat_map(|pair| [pair.0.clone(), pair.1.clone()].into_iter())
+            .collect();
+        packets.push(first_divider.clone());
+        packets.push(second_divider.clone());
+        packets.sort_unstable();
+
+        Ok((packets
+            .iter()
+            .position(|p| p == &first_divider)
+            .unwrap_or_default()
+            + 1)
+            * (packets
+                .iter()
+                .position(|p| p == &second_divider)
+                .unwrap_or_default()
+                + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+    #[test]
+    fn pairs_in_order() {
+        let pairs = Day13.parse(EXAMPLE).unwrap();
+        let in_order: Vec<bool> = pairs.iter().map(|(left, right)| left <= right).collect();
+        assert_eq!(
+            in_order,
+            [true, true, false, true, false, true, false, false]
+        );
+    }
+
+    #[test]
+    fn example() {
+        let pairs = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part1(&pairs).unwrap(), 13);
+        assert_eq!(Day13.part2(&pairs).unwrap(), 140);
+    }
+
+    #[test]
+    fn pairs() {
+        let error = Day13.parse("[1]\n[2]\n[3]\n\n[4]\n[5]\n").unwrap_err();
+        assert!(error.to_string().starts_with("line 3,"), "{error}");
+    }
+}
@@ -0,0 +1,279 @@
+use crate::grid::{Direction, Position};
+use crate::parse::{self, ParseError};
+use crate::position::Screen;
+use crate::solution::{self, Solution};
+use crate::sparse_grid::SparseGrid;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+const SAND_SOURCE: Position = Position::new(500, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+fn draw_line(a: Position, b: Position) -> impl Iterator<Item = Position> {
+    let ax = a.x();
+    let ay = a.y();
+    let bx = b.x();
+    let by = b.y();
+
+    // In this case, we're assuming that we are dealing with horizontal or
+    // vertical lines. We can survive straight diagonals though.
+    (ax.min(bx)..=(ax.max(bx)))
+        .flat_map(move |x| (ay.min(by)..=ay.max(by)).map(move |y| Position::new(x, y)))
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    cave: SparseGrid<Tile, usize, Screen>,
+}
+
+impl Cave {
+    pub fn fill(&mut self, sand_source: Position) -> solution::Result<usize> {
+        let deepest = self.deepest();
+        while !self.cave.contains(sand_source) && self.drop_sand(sand_source, deepest)?.is_some() {}
+
+        Ok(self.cave.values().filter(|&&t| t == Tile::Sand).count())
+    }
+
+    fn deepest(&self) -> usize {
+        self.cave.bounds().map_or(0, |(_, max)| max.y())
+    }
+
+    // Drop a single unit of sand, returning where it comes to rest, unless it
+    // falls past the deepest rock into the abyss.
+    fn drop_sand(
+        &mut self,
+        sand_source: Position,
+        deepest: usize,
+    ) -> solution::Result<Option<Position>> {
+        let mut sand = sand_source;
+
+        while sand.y() <= deepest {
+            match self.fall(sand)? {
+                Some(next) => sand = next,
+                None => {
+                    self.cave.insert(sand, Tile::Sand);
+                    return Ok(Some(sand));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    // Where sand moves next from `sand`, if anywhere.
+    fn fall(&self, sand: Position) -> solution::Result<Option<Position>> {
+        for direction in [Direction::DOWN, Direction::DOWN_LEFT, Direction::DOWN_RIGHT] {
+            let next = (sand + direction).ok_or("Sand flows out of the cave's coordinates.")?;
+            if !self.cave.contains(next) {
+                return Ok(Some(next));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn fill_part2(&mut self, sand_source: Position) -> solution::Result<usize> {
+        let last_empty_row = self.deepest() + 1;
+        // Sand would never come to rest under the floor.
+        if sand_source.y() > last_empty_row {
+            return Err("The sand source is below the floor.".into());
+        }
+        let mut sand = sand_source;
+
+        while !self.cave.contains(sand_source) {
+            if sand.y() == last_empty_row {
+                self.cave.insert(sand, Tile::Sand);
+                sand = sand_source;
+                continue;
+            }
+
+            match self.fall(sand)? {
+                Some(next) => sand = next,
+                None => {
+                    self.cave.insert(sand, Tile::Sand);
+                    sand = sand_source;
+                }
+            }
+        }
+
+        Ok(self.cave.values().filter(|&&t| t == Tile::Sand).count())
+    }
+}
+
+// The part of the cave with rocks or sand in it.
+impl Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.cave
+            .render(|tile| match tile {
+                Some(Tile::Rock) => '#',
+                Some(Tile::Sand) => 'o',
+                None => '.',
+            })
+            .fmt(f)
+    }
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cave = SparseGrid::new();
+
+        for line in s.lines() {
+            let points: Vec<Position> = line
+                .split(" -> ")
+                .map(|point| {
+                    let (x, y) = parse::split_once(s, point, ",")?;
+                    Ok(Position::new(
+                        parse::parse(s, x, "an x position")?,
+                        parse::parse(s, y, "a y position")?,
+                    ))
+                })
+                .collect::<Result<_, _>>()?;
+
+            for pair in points.windows(2) {
+                for p in draw_line(pair[0], pair[1]) {
+                    cave.insert(p, Tile::Rock);
+                }
+            }
+        }
+
+        Ok(Cave { cave })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day14 {
+    sand_source: Position,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14 {
+            sand_source: SAND_SOURCE,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 14;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, cave: &Self::Input) -> solution::Result<Self::Part1> {
+        // Using your scan, simulate the falling sand. How many units of sand
+        // come to rest before sand starts flowing into the abyss below?
+        cave.clone().fill(self.sand_source)
+    }
+
+    fn part2(&self, cave: &Self::Input) -> solution::Result<Self::Part2> {
+        // Using your scan, simulate the falling sand until the source of the
+        // sand becomes blocked. How many units of sand come to rest?
+        cave.clone().fill_part2(self.sand_source)
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        let source = self.sand_source;
+        vec![("sand_source", format!("{},{}", source.x(), source.y()))]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> solution::Result<()> {
+        match name {
+            "sand_source" => {
+                let (x, y) = value
+                    .split_once(',')
+                    .and_then(|(x, y)| Some((x.parse().ok()?, y.parse().ok()?)))
+                    .ok_or_else(|| format!("Invalid value for {name}: {value}."))?;
+                self.sand_source = Position::new(x, y);
+            }
+            _ => return Err(solution::unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+    #[test]
+    fn grains() {
+        let mut cave = Day14::default().parse(EXAMPLE).unwrap();
+        let deepest = cave.deepest();
+        assert_eq!(deepest, 9);
+
+        let resting: Vec<Position> = (0..5)
+            .filter_map(|_| cave.drop_sand(SAND_SOURCE, deepest).unwrap())
+            .collect();
+        assert_eq!(
+            resting,
+            [
+                Position::new(500, 8),
+                Position::new(499, 8),
+                Position::new(501, 8),
+                Position::new(500, 7),
+                Position::new(498, 8),
+            ]
+        );
+
+        // After 24 grains, the next one falls into the abyss.
+        let count = (5..24)
+            .filter_map(|_| cave.drop_sand(SAND_SOURCE, deepest).unwrap())
+            .count();
+        assert_eq!(count, 19);
+        assert_eq!(cave.drop_sand(SAND_SOURCE, deepest).unwrap(), None);
+        assert_eq!(
+            cave.to_string(),
+            "\
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+"
+        );
+    }
+
+    #[test]
+    fn example() {
+        let day = Day14::default();
+        let cave = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&cave).unwrap(), 24);
+        assert_eq!(day.part2(&cave).unwrap(), 93);
+    }
+
+    #[test]
+    fn sand_sources() {
+        let mut day = Day14::default();
+        let cave = day.parse(EXAMPLE).unwrap();
+
+        // Sand piling up against the left edge has nowhere to go.
+        day.set("sand_source", "0,0").unwrap();
+        assert!(day.part2(&cave).is_err());
+
+        // Nothing comes to rest under the floor.
+        day.set("sand_source", "500,1000").unwrap();
+        assert_eq!(day.part1(&cave).unwrap(), 0);
+        assert!(day.part2(&cave).is_err());
+        day.set("sand_source", "500,10").unwrap();
+        assert_eq!(day.part2(&cave).unwrap(), 1);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::parse::{self, ParseError};
+use crate::position::{self, Screen};
+use crate::range_extension::IntervalSet;
+use crate::solution::{self, Solution};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+const TUNING_FREQUENCY: isize = 4_000_000;
+
+type Position = position::Position<isize, Screen>;
+
+type Beacon = Position;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sensor {
+    position: Position,
+    beacon: Beacon,
+}
+
+impl Sensor {
+    pub fn row_coverage(self, row: isize) -> RangeInclusive<isize> {
+        let coverage = self.position.manhattan_distance(self.beacon);
+        let vertical_cost = (self.position.y() - row).abs();
+
+        // Once we have accounted the number of 'steps' to reach the row, this
+        // leaves us with that many steps to either side. Subtract one since
+        // there are never two equidistant beacons.
+        let horizontal_leeway = coverage - vertical_cost;
+        RangeInclusive::new(
+            self.position.x() - horizontal_leeway,
+            self.position.x() + horizontal_leeway,
+        )
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_position = |text| -> Result<Position, ParseError> {
+            let (x, y) = parse::split_once(s, text, ", ")?;
+            Ok(Position::new(
+                parse::parse(s, parse::strip_prefix(s, x, "x=")?, "an x position")?,
+                parse::parse(s, parse::strip_prefix(s, y, "y=")?, "a y position")?,
+            ))
+        };
+
+        let rest = parse::strip_prefix(s, s, "Sensor at ")?;
+        let (sensor, beacon) = parse::split_once(s, rest, ": closest beacon is at ")?;
+        let position = parse_position(sensor)?;
+        let beacon = parse_position(beacon)?;
+
+        Ok(Self { position, beacon })
+    }
+}
+
+fn no_beacon_positions(sensors: &[Sensor], row: isize) -> usize {
+    let covered: IntervalSet<isize> = sensors.iter().map(|s| s.row_coverage(row)).collect();
+    // Remove spots already occupied by a beacon.
+    let occupied: HashSet<isize> = sensors
+        .iter()
+        .filter(|s| s.beacon.y() == row && covered.contains(s.beacon.x()))
+        .map(|s| s.beacon.x())
+        .collect();
+    covered.len() - occupied.len()
+}
+
+fn distress_beacon(sensors: &[Sensor], max: isize) -> Option<Position> {
+    (0..=max).find_map(|y| {
+        let covered: IntervalSet<isize> = sensors.iter().map(|s| s.row_coverage(y)).collect();
+        covered
+            .complement(0..=max)
+            .ranges()
+            .next()
+            .map(|range| Position::new(*range.start(), y))
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day15 {
+    row: isize,
+    max: isize,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            row: 2_000_000,
+            max: 4_000_000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    const DAY: u8 = 15;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(parse::lines(input).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, sensors: &Self::Input) -> solution::Result<Self::Part1> {
+        // Consult the report from the sensors you just deployed. In the row
+        // where y=2000000, how many positions cannot contain a beacon?
+        Ok(no_beacon_positions(sensors, self.row))
+    }
+
+    fn part2(&self, sensors: &Self::Input) -> solution::Result<Self::Part2> {
+        // Find the only possible position for the distress beacon. What is its
+        // tuning frequency?
+        let beacon =
+            distress_beacon(sensors, self.max).ok_or("No position for the distress beacon.")?;
+        Ok(beacon.x() * TUNING_FREQUENCY + beacon.y())
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        vec![("row", self.row.to_string()), ("max", self.max.to_string())]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> solution::Result<()> {
+        match name {
+            "row" => self.row = solution::parameter(name, value)?,
+            "max" => self.max = solution::parameter(name, value)?,
+            _ => return Err(solution::unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    const EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+    #[test]
+    fn example() {
+        // The example uses row 10 and a maximum of 20.
+        let mut day = Day15::default();
+        day.set("row", "10").unwrap();
+        day.set("max", "20").unwrap();
+        let sensors = day.parse(EXAMPLE).unwrap();
+        assert_eq!(no_beacon_positions(&sensors, 10), 26);
+        assert_eq!(distress_beacon(&sensors, 20), Some(Position::new(14, 11)));
+        assert_eq!(day.part1(&sensors).unwrap(), 26);
+        assert_eq!(day.part2(&sensors).unwrap(), 56000011);
+    }
+
+    fn random_sensors(rng: &mut Rng) -> Vec<Sensor> {
+        (0..rng.range(1..=8))
+            .map(|_| {
+                let position = Position::new(rng.range(-5..=25), rng.range(-5..=25));
+                let beacon = loop {
+                    let beacon = Position::new(
+                        position.x() + rng.range(-6..=6),
+                        position.y() + rng.range(-6..=6),
+                    );
+                    if beacon != position {
+                        break beacon;
+                    }
+                };
+                Sensor { position, beacon }
+            })
+            .collect()
+    }
+
+    fn covered(sensors: &[Sensor], p: Position) -> bool {
+        sensors
+            .iter()
+            .any(|s| s.position.manhattan_distance(p) <= s.position.manhattan_distance(s.beacon))
+    }
+
+    #[test]
+    fn no_beacon_positions_match_cells() {
+        let mut rng = Rng::new(16);
+        for _ in 0..200 {
+            let sensors = random_sensors(&mut rng);
+            let row = rng.range(-5..=25);
+            let expected = (-20..=45)
+                .map(|x| Position::new(x, row))
+                .filter(|&p| covered(&sensors, p) && sensors.iter().all(|s| s.beacon != p))
+                .count();
+            assert_eq!(no_beacon_positions(&sensors, row), expected, "{sensors:?}");
+        }
+    }
+
+    #[test]
+    fn distress_beacon_matches_cells() {
+        let mut rng = Rng::new(17);
+        for _ in 0..200 {
+            let sensors = random_sensors(&mut rng);
+            let max = rng.range(0..=20);
+            let expected = (0..=max)
+                .flat_map(|y| (0..=max).map(move |x| Position::new(x, y)))
+                .find(|&p| !covered(&sensors, p));
+            assert_eq!(distress_beacon(&sensors, max), expected, "{sensors:?}");
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use crate::bitset::{BitSet, Universe};
+use crate::parse::{self, ParseError};
+use crate::search;
+use crate::solution::{self, Solution};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::str::FromStr;
+
+const START: &str = "AA";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Valve {
+    name: String,
+    flow_rate: u64,
+    tunnel: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = parse::strip_prefix(s, s, "Valve ")?;
+        let (name, rest) = parse::split_once(s, rest, " has flow rate=")?;
+        let (flow_rate, rest) = parse::split_once(s, rest, "; ")?;
+        // The grammar depends on the number of tunnels.
+        let tunnel = rest
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| rest.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| ParseError::new(s, rest, "tunnels leading to valves"))?
+            .split(", ")
+            .map(str::to_string)
+            .collect();
+
+        Ok(Valve {
+            name: name.to_string(),
+            flow_rate: parse::parse(s, flow_rate, "a flow rate")?,
+            tunnel,
+        })
+    }
+}
+
+fn release_pressure(
+    time_remaining: u64,
+    current: &Valve,
+    open_valves: BitSet,
+    valves: &HashMap<&Valve, Vec<(&Valve, u64)>>,
+    working: &Universe<&Valve>,
+) -> u64 {
+    valves
+        .get(current)
+        .unwrap()
+        .iter()
+        .map(|(valve, steps)| {
+            let bit = working.index(valve).expect("Working valve.");
+            if *steps >= time_remaining || open_valves.contains(bit) {
+                0
+            } else {
+                let mut new_open = open_valves;
+                new_open.insert(bit);
+                let time_remaining = time_remaining - steps;
+                time_remaining * valve.flow_rate
+                    + release_pressure(time_remaining, valve, new_open, valves, working)
+            }
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+fn release_pressure_with_elephant<'valves>(
+    initial_time: u64,
+    current: &'valves Valve,
+    valves: &'valves HashMap<&Valve, Vec<(&Valve, u64)>>,
+    working: &Universe<&Valve>,
+) -> u64 {
+    // We'll generate all the possible paths, with the best pressure outcome.
+    let mut optimal_paths: HashMap<BitSet, u64> = HashMap::new();
+    let mut to_visit = vec![(initial_time, 0, current, BitSet::new())];
+
+    while let Some((time_remaining, pressure, current, open_valves)) = to_visit.pop() {
+        let e = optimal_paths.entry(open_valves).or_default();
+        *e = pressure.max(*e);
+
+        for (valve, steps) in valves.get(current).unwrap() {
+            let bit = working.index(valve).expect("Working valve.");
+            if *steps >= time_remaining || open_valves.contains(bit) {
+                continue;
+            }
+
+            let time_remaining = time_remaining - steps;
+            let mut open_valves = open_valves;
+            open_valves.insert(bit);
+            to_visit.push((
+                time_remaining,
+                pressure + time_remaining * valve.flow_rate,
+                valve,
+                open_valves,
+            ));
+        }
+    }
+
+    // We can create pairs of paths, eliminating those where two paths share
+    // an open valve (which would double count some valves). Then, we only need
+    // to find the best pair.
+    optimal_paths
+        .iter()
+        .flat_map(|(path, pressure)| {
+            optimal_paths
+                .iter()
+                .map(move |(elephant, p2)| (path, elephant, pressure + p2))
+        })
+        .filter_map(|(path, elephant, pressure)| {
+            if path.is_disjoint(elephant) {
+                Some(pressure)
+            } else {
+                None
+            }
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+// The valves worth opening, numbered so that open ones fit in a `BitSet`.
+fn working_valves(valves: &HashMap<String, Valve>) -> solution::Result<Universe<&Valve>> {
+    let working: Universe<&Valve> = valves.values().filter(|v| v.flow_rate > 0).collect();
+    if working.len() > BitSet::<1>::CAPACITY {
+        return Err("Too many working valves.".into());
+    }
+    Ok(working)
+}
+
+fn connect_valves(valves: &HashMap<String, Valve>) -> HashMap<&Valve, Vec<(&Valve, u64)>> {
+    // We can cut down on the size of the problem by skipping broken valves and
+    // adjusting the travel time. This will also eliminate some backwards steps.
+    let mut connected_valves = HashMap::new();
+    for valve in valves.values() {
+        let distances = search::distances([valve], |valve| {
+            valve.tunnel.iter().map(|name| valves.get(name).unwrap())
+        });
+        let connections = distances
+            .into_iter()
+            .filter(|&(other, _)| other != valve && other.flow_rate > 0)
+            // Add 1 to account for the time it takes to open the valve.
+            .map(|(other, steps)| (other, steps as u64 + 1))
+            .collect();
+
+        connected_valves.insert(valve, connections);
+    }
+
+    connected_valves
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = HashMap<String, Valve>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const DAY: u8 = 16;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        let valves: Vec<(&str, Valve)> = input
+            .lines()
+            .zip(parse::lines(input))
+            .map(|(line, valve)| Ok((line, valve?)))
+            .collect::<Result<_, ParseError>>()?;
+
+        // Each valve is described once, and tunnels must lead to known ones.
+        let mut names = HashSet::new();
+        for (line, valve) in &valves {
+            if !names.insert(valve.name.as_str()) {
+                let idx = line.find(valve.name.as_str()).unwrap_or_default();
+                let name = &line[idx..idx + valve.name.len()];
+                return Err(ParseError::new(input, name, "a new valve").into());
+            }
+        }
+        for (line, valve) in &valves {
+            if let Some(tunnel) = valve.tunnel.iter().find(|t| !names.contains(t.as_str())) {
+                let idx = line.rfind(tunnel.as_str()).unwrap_or_default();
+                let tunnel = &line[idx..idx + tunnel.len()];
+                return Err(ParseError::new(input, tunnel, "a known valve").into());
+            }
+        }
+
+        Ok(valves
+            .into_iter()
+            .map(|(_, valve)| (valve.name.to_string(), valve))
+            .collect())
+    }
+
+    fn part1(&self, valves: &Self::Input) -> solution::Result<Self::Part1> {
+        // Work out the steps to release the most pressure in 30 minutes.
+        // What is the most pressure you can release?
+        let connected_valves = connect_valves(valves);
+        Ok(release_pressure(
+            30,
+            valves.get(START).ok_or("Missing starting valve.")?,
+            BitSet::new(),
+            &connected_valves,
+            &working_valves(valves)?,
+        ))
+    }
+
+    fn part2(&self, valves: &Self::Input) -> solution::Result<Self::Part2> {
+        // With you and an elephant working together for 26 minutes, what is the
+        // most pressure you could release?
+        let connected_valves = connect_valves(valves);
+        Ok(release_pressure_with_elephant(
+            26,
+            valves.get(START).ok_or("Missing starting valve.")?,
+            &connected_valves,
+            &working_valves(valves)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+
+    #[test]
+    fn duplicate_valves() {
+        let input = format!("{EXAMPLE}Valve BB has flow rate=1; tunnel leads to valve AA\n");
+        let error = Day16.parse(&input).unwrap_err();
+        assert!(
+            error.to_string().starts_with("line 11, column 7"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn valves() {
+        let valves = Day16.parse(EXAMPLE).unwrap();
+        assert_eq!(valves["HH"].flow_rate, 22);
+        assert_eq!(valves["HH"].tunnel, ["GG"]);
+
+        // Only the working valves remain, with the time needed to open them.
+        let connected_valves = connect_valves(&valves);
+        let mut from_start: Vec<(&str, u64)> = connected_valves[&valves[START]]
+            .iter()
+            .map(|(valve, steps)| (valve.name.as_str(), *steps))
+            .collect();
+        from_start.sort_unstable();
+        assert_eq!(
+            from_start,
+            [
+                ("BB", 2),
+                ("CC", 3),
+                ("DD", 2),
+                ("EE", 3),
+                ("HH", 6),
+                ("JJ", 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn example() {
+        let valves = Day16.parse(EXAMPLE).unwrap();
+        assert_eq!(Day16.part1(&valves).unwrap(), 1651);
+        assert_eq!(Day16.part2(&valves).unwrap(), 1707);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = EXAMPLE.replace("rate=13", "rate=l3");
+        // Parse through the registry, which knows which day this is.
+        let Err(error) = solution::DynSolution::parse(&Day16, &input) else {
+            panic!("Parsed a bad flow rate.");
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 24: expected a flow rate, found \"l3\"."
+        );
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.day(), Some(16));
+        assert_eq!((error.line(), error.column()), (2, 24));
+
+        let input = EXAMPLE.replace("valves CC, AA, EE", "valves CC, AA, ZZ");
+        let error = Day16.parse(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 59: expected a known valve, found \"ZZ\"."
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use crate::grid::{self, Grid};
+use crate::parse::ParseError;
+use crate::periodic;
+use crate::position::{self, Direction, Math};
+use crate::solution::{self, Solution};
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+type Position = position::Position<isize, Math>;
+
+const HORIZONTAL: [Direction; 4] = [
+    Direction::new(0, 0),
+    Direction::new(1, 0),
+    Direction::new(2, 0),
+    Direction::new(3, 0),
+];
+const CROSS: [Direction; 5] = [
+    Direction::new(1, 0),
+    Direction::new(0, 1),
+    Direction::new(1, 1),
+    Direction::new(2, 1),
+    Direction::new(1, 2),
+];
+const CORNER: [Direction; 5] = [
+    Direction::new(0, 0),
+    Direction::new(1, 0),
+    Direction::new(2, 0),
+    Direction::new(2, 1),
+    Direction::new(2, 2),
+];
+const VERTICAL: [Direction; 4] = [
+    Direction::new(0, 0),
+    Direction::new(0, 1),
+    Direction::new(0, 2),
+    Direction::new(0, 3),
+];
+const SQUARE: [Direction; 4] = [
+    Direction::new(0, 0),
+    Direction::new(1, 0),
+    Direction::new(0, 1),
+    Direction::new(1, 1),
+];
+const SHAPES: [&[Direction]; 5] = [&HORIZONTAL, &CROSS, &CORNER, &VERTICAL, &SQUARE];
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Rock {
+    position: Position,
+    shape: &'static [Direction],
+}
+
+impl Rock {
+    pub fn new(position: Position, shape: &'static [Direction]) -> Self {
+        Self { position, shape }
+    }
+
+    pub fn positions(self) -> impl Iterator<Item = Position> {
+        self.shape.iter().map(move |&d| self.position + d)
+    }
+}
+
+impl std::ops::Add<Direction> for Rock {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        Rock::new(self.position + rhs, self.shape)
+    }
+}
+
+// Rocks are not expected to fall any deeper than this below the top of the
+// tower.
+const DEPTH: usize = 256;
+
+#[derive(Debug, Clone)]
+struct Chamber<'a> {
+    jets: &'a [Direction],
+    jet_idx: usize,
+    rocks: usize,
+    cave: HashSet<Position>,
+    highest: isize,
+}
+
+impl<'a> Chamber<'a> {
+    pub fn new(jets: &'a [Direction]) -> Self {
+        Self {
+            jets,
+            jet_idx: 0,
+            rocks: 0,
+            cave: HashSet::new(),
+            highest: 0,
+        }
+    }
+
+    // Drop the next rock until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        let shape = SHAPES[self.rocks % SHAPES.len()];
+        let mut current = Rock::new(Position::new(2, self.highest + 4), shape);
+        self.rocks += 1;
+
+        loop {
+            // Push it, then check that it is not blocked.
+            let lateral = current + self.jets[self.jet_idx];
+            self.jet_idx = (self.jet_idx + 1) % self.jets.len();
+            if lateral
+                .positions()
+                .all(|p| p.x() >= 0 && p.x() <= 6 && !self.cave.contains(&p))
+            {
+                current = lateral;
+            }
+
+            // Let gravity do the work. If it is blocked, add the positions of the
+            // rocks to the cave.
+            let downward = current + Direction::DOWN;
+            if downward
+                .positions()
+                .any(|p| p.y() == 0 || self.cave.contains(&p))
+            {
+                for p in current.positions() {
+                    self.cave.insert(p);
+                    self.highest = self.highest.max(p.y());
+                }
+                return;
+            }
+            current = downward;
+        }
+    }
+
+    // What comes next, and where rocks can still go: whatever is out of reach
+    // does not matter anymore. One row per byte, from the top down.
+    pub fn state(&self) -> (usize, usize, Vec<u8>) {
+        let mut rows = vec![0_u8; DEPTH];
+        let mut to_visit: Vec<Position> =
+            (0..7).map(|x| Position::new(x, self.highest + 1)).collect();
+        while let Some(p) = to_visit.pop() {
+            let depth = (self.highest + 1 - p.y()) as usize;
+            if !(0..7).contains(&p.x())
+                || p.y() <= 0
+                || depth >= DEPTH
+                || rows[depth] & 1 << p.x() != 0
+                || self.cave.contains(&p)
+            {
+                continue;
+            }
+            rows[depth] |= 1 << p.x();
+            to_visit.extend([
+                p + Direction::LEFT,
+                p + Direction::RIGHT,
+                p + Direction::DOWN,
+            ]);
+        }
+        (self.rocks % SHAPES.len(), self.jet_idx, rows)
+    }
+}
+
+// The tower, from the top down.
+impl Display for Chamber<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let height = self.highest as usize;
+        let mut tower = Grid::new(height, 7, vec![false; height * 7]).expect("Sized.");
+        for p in &self.cave {
+            tower[grid::Position::new(p.x() as usize, (self.highest - p.y()) as usize)] = true;
+        }
+        tower.render(|&rock| if rock { '#' } else { '.' }).fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day17 {
+    part1_rocks: usize,
+    part2_rocks: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Day17 {
+            part1_rocks: 2022,
+            part2_rocks: 1_000_000_000_000,
+        }
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Vec<Direction>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    const DAY: u8 = 17;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        let jets = input.trim();
+        if jets.is_empty() {
+            return Err(ParseError::new(input, jets, "a jet (< or >)").into());
+        }
+
+        Ok(jets
+            .char_indices()
+            .map(|(idx, c)| match c {
+                '<' => Ok(Direction::LEFT),
+                '>' => Ok(Direction::RIGHT),
+                _ => Err(ParseError::new(input, &jets[idx..], "a jet (< or >)")),
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, jets: &Self::Input) -> solution::Result<Self::Part1> {
+        // How many units tall will the tower of rocks be after 2022 rocks have
+        // stopped falling?
+        let mut chamber = Chamber::new(jets);
+        for _ in 0..self.part1_rocks {
+            chamber.drop_rock();
+        }
+        Ok(chamber.highest)
+    }
+
+    fn part2(&self, jets: &Self::Input) -> solution::Result<Self::Part2> {
+        // How tall will the tower be after 1000000000000 rocks have stopped?
+        // Ah well, that is a different beast. Let's try and find a repetition,
+        // then use the number of rocks between both to skip ahead (quite) a
+        // bit.
+        Ok(periodic::fast_forward(
+            Chamber::new(jets),
+            self.part2_rocks,
+            Chamber::drop_rock,
+            Chamber::state,
+            |chamber| chamber.highest,
+        ))
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("part1_rocks", self.part1_rocks.to_string()),
+            ("part2_rocks", self.part2_rocks.to_string()),
+        ]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> solution::Result<()> {
+        match name {
+            "part1_rocks" => self.part1_rocks = solution::parameter(name, value)?,
+            "part2_rocks" => self.part2_rocks = solution::parameter(name, value)?,
+            _ => return Err(solution::unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    const EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n";
+
+    #[test]
+    fn example() {
+        let day = Day17::default();
+        let jets = day.parse(EXAMPLE).unwrap();
+        assert_eq!(jets.len(), 40);
+        assert_eq!(day.part1(&jets).unwrap(), 3068);
+        assert_eq!(day.part2(&jets).unwrap(), 1514285714288);
+    }
+
+    #[test]
+    fn tower() {
+        let jets = Day17::default().parse(EXAMPLE).unwrap();
+        let mut chamber = Chamber::new(&jets);
+        chamber.drop_rock();
+        chamber.drop_rock();
+        assert_eq!(chamber.to_string(), "...#...\n..###..\n...#...\n..####.\n");
+    }
+
+    #[test]
+    fn repetition_matches_simulation() {
+        // Rocks only fall in a deep well long after the top of the tower
+        // first repeats.
+        let day = Day17 {
+            part1_rocks: 1337,
+            part2_rocks: 1337,
+        };
+        let jets = day.parse("<>>><<>><<>>><<>").unwrap();
+        assert_eq!(day.part1(&jets).unwrap(), 1931);
+        assert_eq!(day.part2(&jets).unwrap(), 1931);
+
+        let mut rng = Rng::new(17);
+        for _ in 0..20 {
+            let jets: Vec<Direction> = (0..rng.range(1..=60))
+                .map(|_| *rng.choose(&[Direction::LEFT, Direction::RIGHT]))
+                .collect();
+            let rocks = rng.range(0..=3000);
+            let day = Day17 {
+                part1_rocks: rocks,
+                part2_rocks: rocks,
+            };
+            assert_eq!(
+                day.part2(&jets).unwrap(),
+                day.part1(&jets).unwrap(),
+                "{jets:?}, {rocks} rocks"
+            );
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::flood::{self, Component};
+use crate::parse;
+use crate::point::{BoundingBox, Point};
+use crate::solution::{self, Solution};
+use std::collections::HashSet;
+
+pub type Cube = Point<3>;
+
+trait Droplet {
+    fn surface_area(&self) -> usize;
+    fn air_pockets(&self) -> Vec<Component<Cube>>;
+    fn exterior_surface_area(&self) -> usize;
+}
+
+impl Droplet for HashSet<Cube> {
+    fn surface_area(&self) -> usize {
+        self.iter()
+            .flat_map(|cube| cube.orthogonal_neighbours())
+            .filter(|cube| !self.contains(cube))
+            .count()
+    }
+
+    // The air trapped inside the droplet.
+    fn air_pockets(&self) -> Vec<Component<Cube>> {
+        // Make sure our exploration zone has at least one free cube on all sides, but no more.
+        let Some(bounds) = BoundingBox::of(self.iter().copied()) else {
+            return Vec::new();
+        };
+        let exploration_zone = bounds.grow(1);
+        let air = |cube: &Cube| {
+            cube.orthogonal_neighbours()
+                .filter(|&neighbor| {
+                    exploration_zone.contains(neighbor) && !self.contains(&neighbor)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Starting at the very edge, let the water/steam spread until it hits
+        // an edge (or lava). Whatever air it does not reach is trapped.
+        let exterior = flood::flood_fill([exploration_zone.min], air);
+        let trapped = exploration_zone
+            .points()
+            .filter(|cube| !self.contains(cube) && !exterior.contains(cube));
+        flood::connected_components(trapped, air)
+    }
+
+    fn exterior_surface_area(&self) -> usize {
+        // Every face of an air pocket is against the lava, on the inside.
+        let inside: usize = self
+            .air_pockets()
+            .iter()
+            .map(|pocket| pocket.states.surface_area())
+            .sum();
+        self.surface_area() - inside
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Cube>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 18;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(parse::lines(input).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, cubes: &Self::Input) -> solution::Result<Self::Part1> {
+        // What is the surface area of your scanned lava droplet?
+        Ok(cubes.surface_area())
+    }
+
+    fn part2(&self, cubes: &Self::Input) -> solution::Result<Self::Part2> {
+        // What is the exterior surface area of your scanned lava droplet?
+        Ok(cubes.exterior_surface_area())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+";
+
+    #[test]
+    fn two_cubes() {
+        let cubes = Day18.parse("1,1,1\n2,1,1\n").unwrap();
+        assert_eq!(cubes.surface_area(), 10);
+        assert_eq!(cubes.exterior_surface_area(), 10);
+    }
+
+    #[test]
+    fn example() {
+        let cubes = Day18.parse(EXAMPLE).unwrap();
+        assert_eq!(Day18.part1(&cubes).unwrap(), 64);
+        assert_eq!(Day18.part2(&cubes).unwrap(), 58);
+
+        let pockets = cubes.air_pockets();
+        assert_eq!(pockets.len(), 1);
+        let pocket = pockets[0].bounding_box();
+        assert_eq!(
+            (pocket.min, pocket.max),
+            (Cube::new([2, 2, 5]), Cube::new([2, 2, 5]))
+        );
+    }
+}
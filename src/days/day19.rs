@@ -1,7 +1,8 @@
+use crate::parallel;
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
 use std::str::FromStr;
 
-const FILE: &str = "inputs/day19.txt";
-
 #[derive(Debug, Clone, Copy)]
 #[repr(usize)]
 pub enum Ore {
@@ -38,39 +39,39 @@ impl Resources for Ores {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Blueprint {
+pub struct Blueprint {
     id: usize,
     costs: [Cost; 4],
 }
 
 impl FromStr for Blueprint {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (blueprint, robots) = s.split_once(": ").unwrap_or_default();
-        let id = blueprint
-            .split_whitespace()
-            .flat_map(str::parse)
-            .next()
-            .unwrap_or_default();
+        let cost = |text| parse::parse::<usize>(s, text, "a cost");
+        let rest = parse::strip_prefix(s, s, "Blueprint ")?;
+        let (id, rest) = parse::split_once(s, rest, ": ")?;
+        let id = parse::parse(s, id, "a blueprint ID")?;
 
         // Robots always have the same types of costs: ores only for ore and
         // clay robots, ore and clay for obsidian robots, ore and obsidian for
         // geode robots.
-        let mut costs = robots.split_whitespace().flat_map(str::parse);
+        let rest = parse::strip_prefix(s, rest, "Each ore robot costs ")?;
+        let (ore, rest) = parse::split_once(s, rest, " ore. Each clay robot costs ")?;
+        let (clay, rest) = parse::split_once(s, rest, " ore. Each obsidian robot costs ")?;
+        let (obsidian_ore, rest) = parse::split_once(s, rest, " ore and ")?;
+        let (obsidian_clay, rest) = parse::split_once(s, rest, " clay. Each geode robot costs ")?;
+        let (geode_ore, rest) = parse::split_once(s, rest, " ore and ")?;
+        let (geode_obsidian, rest) = parse::split_once(s, rest, " obsidian.")?;
+        if !rest.is_empty() {
+            return Err(ParseError::new(s, rest, "the end of the blueprint"));
+        }
+
         let costs = [
-            [costs.next().unwrap_or_default(), 0, 0],
-            [costs.next().unwrap_or_default(), 0, 0],
-            [
-                costs.next().unwrap_or_default(),
-                costs.next().unwrap_or_default(),
-                0,
-            ],
-            [
-                costs.next().unwrap_or_default(),
-                0,
-                costs.next().unwrap_or_default(),
-            ],
+            [cost(ore)?, 0, 0],
+            [cost(clay)?, 0, 0],
+            [cost(obsidian_ore)?, cost(obsidian_clay)?, 0],
+            [cost(geode_ore)?, 0, cost(geode_obsidian)?],
         ];
 
         Ok(Blueprint { id, costs })
@@ -197,31 +198,98 @@ impl<'blueprint> Factory<'blueprint> {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = std::fs::read_to_string(FILE)?;
-    let blueprints = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<Blueprint>, _>>()?;
-
-    // Determine the quality level of each blueprint using the largest number
-    // of geodes it could produce in 24 minutes. What do you get if you add up
-    // the quality level of all of the blueprints in your list?
-    let part1: usize = blueprints
-        .iter()
-        .map(|blueprint| Factory::new(blueprint).quality_level(24))
-        .sum();
-    println!("Part 1: {part1}");
-
-    // Don't worry about quality levels; instead, just determine the largest
-    // number of geodes you could open using each of the first three blueprints.
-    // What do you get if you multiply these numbers together?
-    let part2: usize = blueprints
-        .iter()
-        .take(3)
-        .map(|blueprint| Factory::new(blueprint).max_geodes(32))
-        .product();
-    println!("Part 2: {part2}");
-
-    Ok(())
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 19;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(parse::lines(input).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, blueprints: &Self::Input) -> solution::Result<Self::Part1> {
+        // Determine the quality level of each blueprint using the largest
+        // number of geodes it could produce in 24 minutes. What do you get if
+        // you add up the quality level of all of the blueprints in your list?
+        // Blueprints are independent from each other.
+        let quality_levels = parallel::map(blueprints, parallel::threads(), |blueprint| {
+            Factory::new(blueprint).quality_level(24)
+        });
+        Ok(quality_levels.into_iter().sum())
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> solution::Result<Self::Part2> {
+        // Don't worry about quality levels; instead, just determine the
+        // largest number of geodes you could open using each of the first
+        // three blueprints. What do you get if you multiply these numbers
+        // together?
+        let first = &blueprints[..blueprints.len().min(3)];
+        let max_geodes = parallel::map(first, parallel::threads(), |blueprint| {
+            Factory::new(blueprint).max_geodes(32)
+        });
+        Ok(max_geodes.into_iter().product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+";
+
+    #[test]
+    fn blueprints() {
+        let blueprints = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(blueprints[0].id, 1);
+        assert_eq!(
+            blueprints[0].costs,
+            [[4, 0, 0], [2, 0, 0], [3, 14, 0], [2, 0, 7]]
+        );
+        assert_eq!(
+            blueprints[1].costs,
+            [[2, 0, 0], [3, 0, 0], [3, 8, 0], [3, 0, 12]]
+        );
+    }
+
+    #[test]
+    fn example() {
+        let blueprints = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(Factory::new(&blueprints[0]).max_geodes(24), 9);
+        assert_eq!(Factory::new(&blueprints[1]).max_geodes(24), 12);
+        assert_eq!(Day19.part1(&blueprints).unwrap(), 33);
+    }
+
+    #[test]
+    #[ignore = "takes minutes without optimizations, use --release"]
+    fn example_part2() {
+        let blueprints = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(Factory::new(&blueprints[0]).max_geodes(32), 56);
+        assert_eq!(Factory::new(&blueprints[1]).max_geodes(32), 62);
+        assert_eq!(Day19.part2(&blueprints).unwrap(), 56 * 62);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = EXAMPLE.replace("and 8 clay", "and x clay");
+        let error = Day19.parse(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 107: expected a cost, found \"x\"."
+        );
+
+        let input = EXAMPLE.replace("Blueprint 2", "Blueprint two");
+        let error = Day19.parse(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 11: expected a blueprint ID, found \"two\"."
+        );
+    }
 }
@@ -1,4 +1,5 @@
-const FILE: &str = "inputs/day2.txt";
+use crate::parse;
+use crate::solution::{self, Solution};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Shape {
@@ -72,33 +73,71 @@ impl From<(Shape, Shape)> for Round {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let rounds = std::fs::read_to_string(FILE)?;
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day2;
 
-    // What would your total score be if everything goes exactly according to
-    // your strategy guide?
-    let part1: u32 = rounds
-        .lines()
-        .map(|round| {
-            let other = Shape::from(round.chars().next().expect("Missing first character."));
-            let mine = Shape::from(round.chars().last().expect("Missing second character."));
-            Round::from((mine, other)) as u32 + mine as u32
-        })
-        .sum();
-    println!("Part1: {part1}");
+impl Solution for Day2 {
+    type Input = Vec<(char, char)>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    // Following the Elf's instructions for the second column, what would your
-    // total score be if everything goes exactly according to your strategy
-    // guide?
-    let part2: u32 = rounds
-        .lines()
-        .map(|round| {
-            let other = Shape::from(round.chars().next().expect("Missing first character."));
-            let round = Round::from(round.chars().last().expect("Missing second character."));
-            Shape::choose_shape(round, other) as u32 + round as u32
-        })
-        .sum();
-    println!("Part2: {part2}");
+    const DAY: u8 = 2;
 
-    Ok(())
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|round| {
+                let (other, mine) = parse::split_once(input, round, " ")?;
+                Ok((
+                    parse::one_of(input, other, "ABC")?,
+                    parse::one_of(input, mine, "XYZ")?,
+                ))
+            })
+            .collect::<Result<_, parse::ParseError>>()?)
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> solution::Result<Self::Part1> {
+        // What would your total score be if everything goes exactly according
+        // to your strategy guide?
+        Ok(rounds
+            .iter()
+            .map(|&(other, mine)| {
+                let other = Shape::from(other);
+                let mine = Shape::from(mine);
+                Round::from((mine, other)) as u32 + mine as u32
+            })
+            .sum())
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> solution::Result<Self::Part2> {
+        // Following the Elf's instructions for the second column, what would
+        // your total score be if everything goes exactly according to your
+        // strategy guide?
+        Ok(rounds
+            .iter()
+            .map(|&(other, round)| {
+                let other = Shape::from(other);
+                let round = Round::from(round);
+                Shape::choose_shape(round, other) as u32 + round as u32
+            })
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z
+";
+
+    #[test]
+    fn example() {
+        let rounds = Day2.parse(EXAMPLE).unwrap();
+        assert_eq!(Day2.part1(&rounds).unwrap(), 15);
+        assert_eq!(Day2.part2(&rounds).unwrap(), 12);
+    }
 }
@@ -0,0 +1,174 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+
+const DECRYPTION_KEY: i64 = 811589153;
+const OFFSETS: [i64; 3] = [1000, 2000, 3000];
+
+fn decrypt(numbers: &[i64], rounds: u32) -> i64 {
+    let length = numbers.len() as i64;
+    let mut positions: Vec<i64> = (0..length).collect();
+
+    // Mixing.
+    for _ in 0..rounds {
+        for (idx, number) in numbers.iter().enumerate() {
+            let start = positions[idx];
+            let end = (start + number).rem_euclid(length - 1);
+            positions.iter_mut().for_each(|p| {
+                if *p > start {
+                    *p -= 1
+                };
+                if *p >= end {
+                    *p += 1
+                };
+            });
+            positions[idx] = end;
+        }
+    }
+
+    // Decryption.
+    let idx_zero = positions[numbers.iter().position(|&n| n == 0).unwrap_or_default()];
+    OFFSETS
+        .into_iter()
+        .map(|offset| {
+            let target = (idx_zero + offset) % numbers.len() as i64;
+            let idx = positions
+                .iter()
+                .position(|&p| p == target)
+                .unwrap_or_default();
+            numbers[idx]
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day20 {
+    decryption_key: i64,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Day20 {
+            decryption_key: DECRYPTION_KEY,
+        }
+    }
+}
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    const DAY: u8 = 20;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        let numbers: Vec<i64> = input
+            .lines()
+            .map(|line| parse::parse(input, line, "a number"))
+            .collect::<Result<_, _>>()?;
+
+        // The grove coordinates are found from the 0, and mixing needs
+        // somewhere to move the numbers to.
+        if numbers.len() < 2 || !numbers.contains(&0) {
+            let end = &input[input.len()..];
+            return Err(ParseError::new(input, end, "at least two numbers, including 0").into());
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> solution::Result<Self::Part1> {
+        // Mix your encrypted file exactly once. What is the sum of the three
+        // numbers that form the grove coordinates?
+        Ok(decrypt(numbers, 1))
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> solution::Result<Self::Part2> {
+        // Apply the decryption key and mix your encrypted file ten times.
+        // What is the sum of the three numbers that form the grove
+        // coordinates?
+        Ok(decrypt(
+            &numbers
+                .iter()
+                .map(|n| n * self.decryption_key)
+                .collect::<Vec<i64>>(),
+            10,
+        ))
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        vec![("decryption_key", self.decryption_key.to_string())]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> solution::Result<()> {
+        match name {
+            "decryption_key" => self.decryption_key = solution::parameter(name, value)?,
+            _ => return Err(solution::unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    const EXAMPLE: &str = "\
+1
+2
+-3
+3
+-2
+0
+4
+";
+
+    #[test]
+    fn example() {
+        let day = Day20::default();
+        let numbers = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&numbers).unwrap(), 3);
+        assert_eq!(day.part2(&numbers).unwrap(), 1623178306);
+    }
+
+    // Move every number by removing it from the file and inserting it back.
+    fn naive_decrypt(numbers: &[i64], rounds: u32) -> i64 {
+        let mut file: Vec<(usize, i64)> = numbers.iter().copied().enumerate().collect();
+        for _ in 0..rounds {
+            for (idx, &number) in numbers.iter().enumerate() {
+                let start = file.iter().position(|&(i, _)| i == idx).unwrap();
+                let moved = file.remove(start);
+                let end = (start as i64 + number).rem_euclid(file.len() as i64);
+                file.insert(end as usize, moved);
+            }
+        }
+
+        let zero = file.iter().position(|&(_, n)| n == 0).unwrap();
+        OFFSETS
+            .into_iter()
+            .map(|offset| file[(zero + offset as usize) % file.len()].1)
+            .sum()
+    }
+
+    #[test]
+    fn decrypt_matches_naive() {
+        let mut rng = Rng::new(20);
+        for _ in 0..200 {
+            let length = rng.range(2..=20);
+            let key = *rng.choose(&[1, DECRYPTION_KEY]);
+            let mut numbers: Vec<i64> = (1..length).map(|_| rng.range(-50..=50) * key).collect();
+            numbers.retain(|&n| n != 0);
+            numbers.insert(rng.range(0..=numbers.len()), 0);
+            if numbers.len() < 2 {
+                continue;
+            }
+
+            let rounds = rng.range(1..=3);
+            assert_eq!(
+                decrypt(&numbers, rounds),
+                naive_decrypt(&numbers, rounds),
+                "{numbers:?}, {rounds} rounds"
+            );
+        }
+    }
+}
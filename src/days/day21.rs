@@ -0,0 +1,340 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+const ROOT: &str = "root";
+const HUMN: &str = "humn";
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "+" => Operation::Add,
+            "-" => Operation::Subtract,
+            "*" => Operation::Multiply,
+            "/" => Operation::Divide,
+            _ => Err(ParseError::new(s, s, "+, -, * or /"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Monkey {
+    Number(i64),
+    Operation(String, String, Operation),
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(number) = s.parse() {
+            Ok(Monkey::Number(number))
+        } else {
+            let mut iter = s.split_whitespace();
+            match (iter.next(), iter.next(), iter.next(), iter.next()) {
+                (Some(a), Some(op), Some(b), None) => {
+                    let op = op.parse().map_err(|e: ParseError| e.within(s, op))?;
+                    // Monkeys only wait on other monkeys.
+                    if let Some(number) = [a, b].into_iter().find(|n| n.parse::<i64>().is_ok()) {
+                        return Err(ParseError::new(s, number, "a monkey's name"));
+                    }
+                    Ok(Monkey::Operation(a.to_string(), b.to_string(), op))
+                }
+                _ => Err(ParseError::new(s, s, "a number or an operation")),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkeys(HashMap<String, Monkey>);
+
+impl Monkeys {
+    pub fn entry(&mut self, name: String) -> Entry<'_, String, Monkey> {
+        self.0.entry(name)
+    }
+
+    pub fn insert(&mut self, name: String, monkey: Monkey) {
+        self.0.insert(name, monkey);
+    }
+
+    pub fn get(&self, name: &str) -> solution::Result<i64> {
+        Ok(match &self.0[name] {
+            Monkey::Number(n) => *n,
+            Monkey::Operation(a, b, op) => {
+                let (a, b) = (self.get(a)?, self.get(b)?);
+                match op {
+                    Operation::Add => a.checked_add(b),
+                    Operation::Subtract => a.checked_sub(b),
+                    Operation::Multiply => a.checked_mul(b),
+                    Operation::Divide if b == 0 => {
+                        return Err(format!("Monkey {name} divides by 0.").into())
+                    }
+                    Operation::Divide => a.checked_div(b),
+                }
+                .ok_or_else(|| format!("Monkey {name} yells a number too large."))?
+            }
+        })
+    }
+}
+
+impl FromStr for Monkeys {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut h = HashMap::new();
+        // What each monkey waits on, as written, in order.
+        let mut operands = Vec::new();
+
+        for line in s.lines() {
+            let (name, rest) = parse::split_once(s, line, ": ")?;
+            let monkey = rest.parse().map_err(|e: ParseError| e.within(s, rest))?;
+            if let Monkey::Operation(..) = monkey {
+                operands.push((name, rest.split_whitespace().step_by(2).collect::<Vec<_>>()));
+            }
+            if h.insert(name.to_string(), monkey).is_some() {
+                return Err(ParseError::new(s, name, "a new monkey"));
+            }
+        }
+
+        // Monkeys can only wait on monkeys that exist, starting with root.
+        for (_, names) in &operands {
+            if let Some(name) = names.iter().find(|name| !h.contains_key(**name)) {
+                return Err(ParseError::new(s, name, "a known monkey"));
+            }
+        }
+        if !h.contains_key(ROOT) {
+            return Err(ParseError::new(s, &s[s.len()..], "a monkey named root"));
+        }
+
+        // Nor can they end up waiting on themselves.
+        let operands: HashMap<&str, Vec<&str>> = operands.into_iter().collect();
+        let mut done = HashSet::new();
+        for line in s.lines() {
+            let (name, _) = line.split_once(": ").expect("Checked above.");
+            if let Some(name) = find_cycle(name, &operands, &mut HashSet::new(), &mut done) {
+                return Err(ParseError::new(
+                    s,
+                    name,
+                    "a monkey that does not wait on itself",
+                ));
+            }
+        }
+
+        Ok(Monkeys(h))
+    }
+}
+
+// Where a monkey waiting on `name`, or on those it waits on, is already one of
+// the `waiting` ones, unless they are `done`.
+fn find_cycle<'a>(
+    name: &'a str,
+    operands: &HashMap<&'a str, Vec<&'a str>>,
+    waiting: &mut HashSet<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+    if done.contains(name) {
+        return None;
+    }
+    waiting.insert(name);
+    for &operand in operands.get(name).into_iter().flatten() {
+        if waiting.contains(&operand) {
+            return Some(operand);
+        }
+        if let Some(cycle) = find_cycle(operand, operands, waiting, done) {
+            return Some(cycle);
+        }
+    }
+    waiting.remove(name);
+    done.insert(name);
+    None
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Monkeys;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    const DAY: u8 = 21;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> solution::Result<Self::Part1> {
+        // However, your actual situation involves considerably more monkeys.
+        // What number will the monkey named root yell?
+        monkeys.get(ROOT)
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> solution::Result<Self::Part2> {
+        // What number do you yell to pass root's equality test?
+        // Equals just means no *difference*, so we'll just replace the
+        // operation for root with a difference, and try to find 0.
+        let mut monkeys = monkeys.clone();
+        monkeys.entry(ROOT.to_string()).and_modify(|root| {
+            if let Monkey::Operation(_, _, op) = root {
+                *op = Operation::Subtract;
+            }
+        });
+
+        let mut yell = |humn| {
+            monkeys.insert(HUMN.to_string(), Monkey::Number(humn));
+            monkeys.get(ROOT)
+        };
+
+        // Try a binary search. Depending on the input, ROOT goes either down
+        // or up as HUMN increases (it goes down for mine, up for the example),
+        // so check first and flip the sign of the difference as needed.
+        // Note that we get plateaus when using integers, and that, while not
+        // explicitly mentioned in the problem, we need to return the smallest
+        // valid number (confirmed when using floats).
+        let mut left = 0;
+        let mut right = i64::MAX >> 6;
+        let sign = if yell(left)? < yell((left + right) / 2)? {
+            -1
+        } else {
+            1
+        };
+
+        while left < right {
+            let mid = (left + right) / 2;
+            if sign * yell(mid)? > 0 {
+                left = mid + 1;
+            } else {
+                right = mid;
+            }
+        }
+
+        if yell(left)? == 0 {
+            Ok(left)
+        } else {
+            Err("No number passes root's equality test.".into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate;
+
+    const EXAMPLE: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32
+";
+
+    #[test]
+    fn example() {
+        let monkeys = Day21.parse(EXAMPLE).unwrap();
+        assert_eq!(monkeys.get("pppw").unwrap(), 2);
+        assert_eq!(monkeys.get("sjmn").unwrap(), 150);
+        assert_eq!(Day21.part1(&monkeys).unwrap(), 152);
+        assert_eq!(Day21.part2(&monkeys).unwrap(), 301);
+    }
+
+    // What a monkey yells as (a * humn + b) / d, exactly, unless humn's
+    // number is multiplied by itself or divides something.
+    fn linear(monkeys: &Monkeys, name: &str) -> Option<(i128, i128, i128)> {
+        if name == HUMN {
+            return Some((1, 0, 1));
+        }
+        let (a, b, d) = match monkeys.0.get(name)? {
+            Monkey::Number(n) => (0, i128::from(*n), 1),
+            Monkey::Operation(left, right, op) => {
+                let (a0, b0, d0) = linear(monkeys, left)?;
+                let (a1, b1, d1) = linear(monkeys, right)?;
+                match op {
+                    Operation::Add => (a0 * d1 + a1 * d0, b0 * d1 + b1 * d0, d0 * d1),
+                    Operation::Subtract => (a0 * d1 - a1 * d0, b0 * d1 - b1 * d0, d0 * d1),
+                    Operation::Multiply if a0 == 0 || a1 == 0 => {
+                        (a0 * b1 + a1 * b0, b0 * b1, d0 * d1)
+                    }
+                    Operation::Divide if a1 == 0 && b1 != 0 => (a0 * d1, b0 * d1, d0 * b1),
+                    _ => return None,
+                }
+            }
+        };
+
+        let gcd = [a, b, d].into_iter().fold(0_i128, |x, y| {
+            let (mut x, mut y) = (x.abs(), y.abs());
+            while y != 0 {
+                (x, y) = (y, x % y);
+            }
+            x
+        });
+        Some((a / gcd, b / gcd, d / gcd))
+    }
+
+    fn exact_humn(monkeys: &Monkeys) -> Option<i64> {
+        let Some(Monkey::Operation(left, right, _)) = monkeys.0.get(ROOT) else {
+            return None;
+        };
+        let (a0, b0, d0) = linear(monkeys, left)?;
+        let (a1, b1, d1) = linear(monkeys, right)?;
+        let (a, b) = (a0 * d1 - a1 * d0, b1 * d0 - b0 * d1);
+        (a != 0 && b % a == 0).then(|| (b / a) as i64)
+    }
+
+    #[test]
+    fn binary_search_matches_exact() {
+        let example = Day21.parse(EXAMPLE).unwrap();
+        assert_eq!(exact_humn(&example), Some(301));
+
+        // Divisions are exact in generated inputs, as in ours, so that there
+        // is no plateau around the answer.
+        let generator = generate::get(Day21::DAY).unwrap();
+        for seed in 0..50 {
+            let input = generator.generate(seed, 20 * (seed as usize % 10 + 1));
+            let monkeys = Day21.parse(&input).unwrap();
+            let humn = exact_humn(&monkeys).unwrap();
+            assert_eq!(Day21.part2(&monkeys).unwrap(), humn, "{input}");
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input: &str| Day21.parse(input).unwrap_err().to_string();
+        assert!(error("root: 5 / zzzz\nzzzz: 1\n").contains("a monkey's name"));
+        assert!(error("root: aaaa + bbbb\naaaa: bbbb * root\nbbbb: 2\n")
+            .contains("does not wait on itself"));
+        assert!(error("root: 1\nroot: 2\n").contains("line 2, column 1"));
+
+        let monkeys = Day21
+            .parse("root: aaaa / bbbb\naaaa: 1\nbbbb: 0\n")
+            .unwrap();
+        assert!(Day21.part1(&monkeys).is_err());
+        let monkeys = Day21
+            .parse("root: aaaa * aaaa\naaaa: 9999999999999\n")
+            .unwrap();
+        assert!(Day21.part1(&monkeys).is_err());
+    }
+}
@@ -0,0 +1,422 @@
+use crate::grid::{Direction, Grid, Position};
+use crate::parse::{self, ParseError};
+use crate::point::Point;
+use crate::solution::{self, Solution};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            _ => Err(value),
+        }
+    }
+}
+
+// Spaces are off the map.
+type MonkeyMap = Grid<Option<Tile>>;
+
+fn parse_map(s: &str) -> Result<MonkeyMap, ParseError> {
+    Grid::parse_with(s, Some(None), |c| match Tile::try_from(c) {
+        Ok(tile) => Ok(Some(tile)),
+        Err(' ') => Ok(None),
+        Err(_) => Err("., # or a space"),
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Steps(u8),
+    TurnLeft,
+    TurnRight,
+}
+
+fn parse_instructions(source: &str, s: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+    let mut rest = s.trim();
+    while let Some(c) = rest.chars().next() {
+        match c {
+            'L' => instructions.push(Instruction::TurnLeft),
+            'R' => instructions.push(Instruction::TurnRight),
+            '0'..='9' => {
+                let end = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                let steps = parse::parse(source, &rest[..end], "a number of steps")?;
+                instructions.push(Instruction::Steps(steps));
+                rest = &rest[end..];
+                continue;
+            }
+            _ => return Err(ParseError::new(source, rest, "a number of steps, L or R")),
+        }
+        rest = &rest[1..];
+    }
+
+    Ok(instructions)
+}
+
+fn password(position: Position, direction: Direction) -> usize {
+    1000 * (position.y() + 1)
+        + 4 * (position.x() + 1)
+        + match direction {
+            Direction::RIGHT => 0,
+            Direction::DOWN => 1,
+            Direction::LEFT => 2,
+            Direction::UP => 3,
+            _ => unreachable!(),
+        }
+}
+
+// The leftmost open tile of the top row.
+fn start(map: &MonkeyMap) -> Position {
+    map.iter()
+        .find(|&(_, tile)| *tile == Some(Tile::Open))
+        .map(|(position, _)| position)
+        .unwrap_or_default()
+}
+
+fn walk_flat(instructions: &[Instruction], map: &MonkeyMap) -> usize {
+    let mut direction = Direction::RIGHT;
+    let mut position = start(map);
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Steps(steps) => {
+                'outer: for _ in 0..*steps {
+                    let mut next = position;
+                    loop {
+                        // Wrap around the map until we are back on it.
+                        next = match (next + direction).filter(|&p| map.contains(p)) {
+                            Some(next) => next,
+                            None => match direction {
+                                Direction::UP => Position::new(next.x(), map.height() - 1),
+                                Direction::DOWN => Position::new(next.x(), 0),
+                                Direction::LEFT => Position::new(map.width() - 1, next.y()),
+                                Direction::RIGHT => Position::new(0, next.y()),
+                                _ => unreachable!(),
+                            },
+                        };
+                        match map[next] {
+                            Some(Tile::Open) => {
+                                position = next;
+                                break;
+                            }
+                            Some(Tile::Wall) => break 'outer,
+                            None => (),
+                        }
+                    }
+                }
+            }
+            Instruction::TurnLeft => direction = direction.rotate_left(),
+            Instruction::TurnRight => direction = direction.rotate_right(),
+        }
+    }
+
+    password(position, direction)
+}
+
+// A face of the cube, identified by its column and row on the net.
+type Face = Position;
+
+// How a face lies on the cube: which way it faces, and where going right and
+// down on the map leads.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    normal: Point<3>,
+    right: Point<3>,
+    down: Point<3>,
+}
+
+impl Frame {
+    // Where going in `direction` on the face leads on the cube.
+    fn towards(self, direction: Direction) -> Point<3> {
+        match direction {
+            Direction::RIGHT => self.right,
+            Direction::LEFT => -self.right,
+            Direction::DOWN => self.down,
+            Direction::UP => -self.down,
+            _ => unreachable!(),
+        }
+    }
+
+    // The face next to this one in `direction`, folded over the edge: going
+    // on in that direction then leads away from this face.
+    fn fold(self, direction: Direction) -> Self {
+        let normal = self.towards(direction);
+        match direction {
+            Direction::RIGHT => Frame {
+                normal,
+                right: -self.normal,
+                down: self.down,
+            },
+            Direction::LEFT => Frame {
+                normal,
+                right: self.normal,
+                down: self.down,
+            },
+            Direction::DOWN => Frame {
+                normal,
+                right: self.right,
+                down: -self.normal,
+            },
+            Direction::UP => Frame {
+                normal,
+                right: self.right,
+                down: self.normal,
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Cube {
+    size: usize,
+    // Walking off a face in a direction leads onto another face, in a new
+    // direction.
+    edges: HashMap<(Face, Direction), (Face, Direction)>,
+}
+
+impl Cube {
+    pub fn new(map: &MonkeyMap) -> Option<Self> {
+        // Six faces of size * size tiles.
+        let tiles = map.iter().filter(|(_, tile)| tile.is_some()).count();
+        let size = (1..=tiles)
+            .find(|size| 6 * size * size >= tiles)
+            .filter(|size| 6 * size * size == tiles)?;
+        let mut faces: Vec<Face> = map
+            .iter()
+            .filter(|(_, tile)| tile.is_some())
+            .map(|(p, _)| Position::new(p.x() / size, p.y() / size))
+            .collect();
+        faces.sort_unstable();
+        faces.dedup();
+
+        // Fold the net around the first face, one neighbour at a time.
+        let first = Frame {
+            normal: Point::new([0, 0, 1]),
+            right: Point::new([1, 0, 0]),
+            down: Point::new([0, 1, 0]),
+        };
+        let mut frames = HashMap::from([(*faces.first()?, first)]);
+        let mut to_visit = vec![*faces.first()?];
+        while let Some(face) = to_visit.pop() {
+            let frame = frames[&face];
+            for direction in Direction::ORTHOGONAL {
+                let Some(next) = (face + direction).filter(|next| faces.contains(next)) else {
+                    continue;
+                };
+                if let Entry::Vacant(entry) = frames.entry(next) {
+                    entry.insert(frame.fold(direction));
+                    to_visit.push(next);
+                }
+            }
+        }
+
+        // All six sides of the cube must be covered, once each.
+        let mut normals: Vec<Point<3>> = frames.values().map(|frame| frame.normal).collect();
+        normals.sort_unstable();
+        normals.dedup();
+        if faces.len() != 6 || frames.len() != 6 || normals.len() != 6 {
+            return None;
+        }
+
+        // Going over an edge leads to the face on that side, moving away from
+        // the one we left.
+        let mut edges = HashMap::new();
+        for (&face, &frame) in &frames {
+            for direction in Direction::ORTHOGONAL {
+                let side = frame.towards(direction);
+                let (&to, &to_frame) = frames.iter().find(|(_, f)| f.normal == side)?;
+                let to_direction = Direction::ORTHOGONAL
+                    .into_iter()
+                    .find(|&d| to_frame.towards(d) == -frame.normal)?;
+                edges.insert((face, direction), (to, to_direction));
+            }
+        }
+
+        Some(Cube { size, edges })
+    }
+
+    // Walk off the edge of a face onto the adjacent one.
+    pub fn wrap(&self, position: Position, direction: Direction) -> (Position, Direction) {
+        let n = self.size;
+        let face = Position::new(position.x() / n, position.y() / n);
+        let (mut x, mut y) = (position.x() % n, position.y() % n);
+        let &(to, to_direction) = self
+            .edges
+            .get(&(face, direction))
+            .unwrap_or_else(|| panic!("Wrong path: {position:?}, {direction:?}"));
+
+        // Turn the face until we are facing the right way, then enter from
+        // the opposite edge.
+        let mut current = direction;
+        while current != to_direction {
+            (x, y) = (n - 1 - y, x);
+            current = current.rotate_right();
+        }
+        match to_direction {
+            Direction::RIGHT => x = 0,
+            Direction::LEFT => x = n - 1,
+            Direction::DOWN => y = 0,
+            Direction::UP => y = n - 1,
+            _ => unreachable!(),
+        }
+
+        (Position::new(to.x() * n + x, to.y() * n + y), to_direction)
+    }
+}
+
+fn walk_cube(instructions: &[Instruction], map: &MonkeyMap, cube: &Cube) -> usize {
+    let mut direction = Direction::RIGHT;
+    let mut position = start(map);
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Steps(steps) => {
+                for _ in 0..*steps {
+                    let (next, next_direction) = match (position + direction)
+                        .filter(|&next| map.get(next).is_some_and(Option::is_some))
+                    {
+                        Some(next) => (next, direction),
+                        None => cube.wrap(position, direction),
+                    };
+
+                    if map[next] == Some(Tile::Wall) {
+                        break;
+                    }
+                    position = next;
+                    direction = next_direction;
+                }
+            }
+            Instruction::TurnLeft => direction = direction.rotate_left(),
+            Instruction::TurnRight => direction = direction.rotate_right(),
+        }
+    }
+
+    password(position, direction)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (MonkeyMap, Vec<Instruction>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 22;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        let (map, instructions) = parse::split_once(input, input, "\n\n")?;
+        let first_row = map.lines().next().unwrap_or(map);
+        let map = parse_map(map)?;
+        // That's where we start.
+        if !first_row.contains('.') {
+            return Err(ParseError::new(input, first_row, "an open tile on the first row").into());
+        }
+
+        Ok((map, parse_instructions(input, instructions)?))
+    }
+
+    fn part1(&self, (map, instructions): &Self::Input) -> solution::Result<Self::Part1> {
+        // Follow the path given in the monkeys' notes. What is the final
+        // password?
+        Ok(walk_flat(instructions, map))
+    }
+
+    fn part2(&self, (map, instructions): &Self::Input) -> solution::Result<Self::Part2> {
+        // Fold the map into a cube, then follow the path given in the monkeys'
+        // notes. What is the final password?
+        let cube = Cube::new(map).ok_or("The map does not fold into a cube.")?;
+        Ok(walk_cube(instructions, map, &cube))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate;
+
+    // The leading spaces are significant.
+    const EXAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+";
+
+    #[test]
+    fn cube() {
+        let (map, _) = Day22.parse(EXAMPLE).unwrap();
+        let cube = Cube::new(&map).unwrap();
+        assert_eq!(cube.size, 4);
+
+        // Walking off the right of the middle face lands on top of the one
+        // on the bottom right, facing down.
+        assert_eq!(
+            cube.wrap(Position::new(11, 5), Direction::RIGHT),
+            (Position::new(14, 8), Direction::DOWN)
+        );
+        // And back again.
+        assert_eq!(
+            cube.wrap(Position::new(14, 8), Direction::UP),
+            (Position::new(11, 5), Direction::LEFT)
+        );
+    }
+
+    #[test]
+    fn example() {
+        let input = Day22.parse(EXAMPLE).unwrap();
+        assert_eq!(Day22.part1(&input).unwrap(), 6032);
+        assert_eq!(Day22.part2(&input).unwrap(), 5031);
+    }
+
+    #[test]
+    fn any_net() {
+        // Walking off an edge, then turning back, leads back where we were.
+        let generator = generate::get(Day22::DAY).unwrap();
+        for seed in 0..100 {
+            let input = generator.generate(seed, 3);
+            let (map, _) = Day22.parse(&input).unwrap();
+            let cube = Cube::new(&map).unwrap();
+            for (position, _) in map.iter().filter(|(_, tile)| tile.is_some()) {
+                for direction in Direction::ORTHOGONAL {
+                    let next = (position + direction).and_then(|p| map.get(p).copied().flatten());
+                    if next.is_none() {
+                        let (back, facing) = cube.wrap(position, direction);
+                        assert_eq!(
+                            cube.wrap(back, facing.reverse()),
+                            (position, direction.reverse()),
+                            "{input}"
+                        );
+                    }
+                }
+            }
+        }
+
+        // Six faces do not always make a cube.
+        let (map, _) = Day22.parse("...\n...\n\n1").unwrap();
+        assert!(Cube::new(&map).is_none());
+    }
+}
@@ -0,0 +1,213 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::position::{self, Direction, Screen};
+use crate::solution::{self, Solution};
+use crate::sparse_grid::SparseGrid;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+type Position = position::Position<isize, Screen>;
+
+const MOVES: [[Direction; 3]; 4] = [
+    ([Direction::UP_LEFT, Direction::UP, Direction::UP_RIGHT]),
+    ([Direction::DOWN_LEFT, Direction::DOWN, Direction::DOWN_RIGHT]),
+    ([Direction::UP_LEFT, Direction::LEFT, Direction::DOWN_LEFT]),
+    ([Direction::UP_RIGHT, Direction::RIGHT, Direction::DOWN_RIGHT]),
+];
+const ELF: char = '#';
+const GROUND: char = '.';
+
+#[derive(Debug, Default, Clone)]
+pub struct Grove {
+    round: usize,
+    elves: SparseGrid<()>,
+}
+
+impl Iterator for Grove {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The elves will consider NORTH (UP) first, then SOUTH (DOWN) first,
+        // etc.
+        let moves = MOVES.into_iter().cycle().skip(self.round).take(4);
+
+        let mut counts: HashMap<Position, u32> = HashMap::new();
+        let mut no_neighbours: usize = 0;
+        let propositions: HashMap<Position, Position> = self
+            .elves
+            .positions()
+            .filter_map(|elf| {
+                // If we have no neighbors, don't try to move. Otherwise, find
+                // the first proposition available.
+                if !Direction::ALL
+                    .into_iter()
+                    .any(|d| self.elves.contains(elf + d))
+                {
+                    no_neighbours += 1;
+                    None
+                } else {
+                    moves.clone().find_map(|checks| {
+                        if !checks.into_iter().any(|d| self.elves.contains(elf + d)) {
+                            let new_position = elf + checks[1];
+                            *counts.entry(new_position).or_default() += 1;
+                            Some((elf, new_position))
+                        } else {
+                            None
+                        }
+                    })
+                }
+            })
+            .collect();
+
+        // We're done once the elves no longer try to move.
+        if no_neighbours == self.elves.len() {
+            None
+        } else {
+            let mut new_elves = self
+                .elves
+                .positions()
+                .map(|elf| {
+                    let elf = propositions
+                        .get(&elf)
+                        .filter(|proposed| counts.get(proposed).copied().unwrap_or_default() == 1)
+                        .copied()
+                        .unwrap_or(elf);
+                    (elf, ())
+                })
+                .collect();
+            std::mem::swap(&mut new_elves, &mut self.elves);
+            self.round += 1;
+            Some(self.round)
+        }
+    }
+}
+
+impl FromStr for Grove {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grove = Grid::parse_with(s, Some(false), |c| match c {
+            ELF => Ok(true),
+            GROUND => Ok(false),
+            _ => Err("an elf (#) or ground (.)"),
+        })?;
+        let elves = grove
+            .iter()
+            .filter(|&(_, &elf)| elf)
+            .map(|(p, _)| (p.try_cast().expect("Fits."), ()))
+            .collect();
+
+        Ok(Grove { round: 0, elves })
+    }
+}
+
+// The smallest rectangle holding every elf.
+impl Display for Grove {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.elves
+            .render(|elf| if elf.is_some() { ELF } else { GROUND })
+            .fmt(f)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grove;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 23;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, elves: &Self::Input) -> solution::Result<Self::Part1> {
+        // Simulate the Elves' process and find the smallest rectangle that
+        // contains the Elves after 10 rounds. How many empty ground tiles does
+        // that rectangle contain?
+        let mut elves = elves.clone();
+        elves.nth(9);
+        Ok(elves.elves.empty_cells())
+    }
+
+    fn part2(&self, elves: &Self::Input) -> solution::Result<Self::Part2> {
+        // Figure out where the Elves need to go. What is the number of the
+        // first round where no Elf moves?
+        Ok(elves.clone().last().unwrap_or_default() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..
+";
+
+    const AFTER_ROUND_10: &str = "\
+.......#......
+...........#..
+..#.#..#......
+......#.......
+...#.....#..#.
+.#......##....
+.....##.......
+..#........#..
+....#.#..#....
+..............
+....#..#..#...
+..............
+";
+
+    // Shift the elves so that the top-left one is at the origin.
+    fn normalized(grove: &Grove) -> HashSet<Position> {
+        let (min, _) = grove.elves.bounds().unwrap_or_default();
+        grove
+            .elves
+            .positions()
+            .map(|p| Position::new(p.x() - min.x(), p.y() - min.y()))
+            .collect()
+    }
+
+    #[test]
+    fn board_after_round_10() {
+        let mut grove = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(grove.nth(9), Some(10));
+
+        let expected: Grove = AFTER_ROUND_10.parse().unwrap();
+        assert_eq!(normalized(&grove), normalized(&expected));
+        assert_eq!(grove.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn display() {
+        let mut grove: Grove = ".....\n..##.\n..#..\n.....\n..##.\n.....\n"
+            .parse()
+            .unwrap();
+        assert_eq!(grove.to_string(), "##\n#.\n..\n##\n");
+        grove.nth(2);
+        assert_eq!(
+            grove.to_string(),
+            "..#..\n....#\n#....\n....#\n.....\n..#..\n"
+        );
+    }
+
+    #[test]
+    fn example() {
+        let grove = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(Day23.part1(&grove).unwrap(), 110);
+        assert_eq!(Day23.part2(&grove).unwrap(), 20);
+    }
+}
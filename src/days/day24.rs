@@ -0,0 +1,260 @@
+use crate::grid::{Direction, Grid, Position, Torus};
+use crate::parse::ParseError;
+use crate::periodic::PeriodicCache;
+use crate::search;
+use crate::solution::{self, Solution};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+struct Blizzard {
+    position: Position,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone)]
+pub struct Valley {
+    walls: Grid<bool>,
+    // Blizzards wrap around the inside of the walls.
+    inside: Torus,
+    blizzards: Vec<Blizzard>,
+    minute: usize,
+    states: PeriodicCache<Grid<bool>>,
+}
+
+impl Valley {
+    pub fn get_state(&mut self, time: usize) -> &Grid<bool> {
+        // The valley is back as it started once every blizzard is.
+        let (walls, inside, blizzards) = (&self.walls, self.inside, &self.blizzards);
+        self.states.get_or_insert_with(time, |time| {
+            let mut state = walls.map(|_| false);
+            for position in Self::positions(inside, blizzards, time) {
+                state[position] = true;
+            }
+            state
+        })
+    }
+
+    pub fn next(&mut self) {
+        self.minute += 1;
+    }
+
+    // Where each blizzard is at `time`.
+    fn positions(
+        inside: Torus,
+        blizzards: &[Blizzard],
+        time: usize,
+    ) -> impl Iterator<Item = Position> + '_ {
+        blizzards
+            .iter()
+            .map(move |b| inside.advance(b.position, b.direction, time))
+    }
+}
+
+const BLIZZARDS: [(char, Direction); 4] = [
+    ('^', Direction::UP),
+    ('v', Direction::DOWN),
+    ('<', Direction::LEFT),
+    ('>', Direction::RIGHT),
+];
+
+impl FromStr for Valley {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse_with(s, None, |c| match c {
+            '#' | '.' | '^' | 'v' | '<' | '>' => Ok(c),
+            _ => Err("#, ., ^, v, < or >"),
+        })?;
+        // There must be room for the walls around the valley.
+        if tiles.height() < 3 || tiles.width() < 3 {
+            return Err(ParseError::new(s, s, "a valley surrounded by walls"));
+        }
+
+        // The tile at some position, to point at in errors (all are ASCII).
+        let tile = |position: Position| {
+            let line = s.lines().nth(position.y()).expect("Within the valley.");
+            &line[position.x()..=position.x()]
+        };
+
+        // We come in at the top left and leave at the bottom right.
+        let start = Position::new(1, 0);
+        let end = Position::new(tiles.width() - 2, tiles.height() - 1);
+        for position in [start, end] {
+            if tiles[position] != '.' {
+                return Err(ParseError::new(s, tile(position), "an opening (.)"));
+            }
+        }
+
+        let inside = Torus::new(Position::new(1, 1), tiles.width() - 2, tiles.height() - 2);
+        let mut blizzards = Vec::new();
+        for (position, &c) in tiles.iter() {
+            if let Some((_, direction)) = BLIZZARDS.into_iter().find(|&(b, _)| b == c) {
+                if !inside.contains(position) {
+                    return Err(ParseError::new(
+                        s,
+                        tile(position),
+                        "a wall (#) or an opening (.)",
+                    ));
+                }
+                blizzards.push(Blizzard {
+                    position,
+                    direction,
+                });
+            }
+        }
+        Ok(Self {
+            walls: tiles.map(|&tile| tile == '#'),
+            inside,
+            blizzards,
+            minute: 0,
+            states: PeriodicCache::new(inside.period()),
+        })
+    }
+}
+
+// The valley as it is at the current minute, with the number of blizzards
+// where several of them meet.
+impl Display for Valley {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut tiles = self.walls.map(|&wall| if wall { '#' } else { '.' });
+        let positions = Self::positions(self.inside, &self.blizzards, self.minute);
+        for (blizzard, position) in self.blizzards.iter().zip(positions) {
+            let tile = &mut tiles[position];
+            *tile = match *tile {
+                '.' => BLIZZARDS
+                    .into_iter()
+                    .find(|&(_, direction)| direction == blizzard.direction)
+                    .map_or('?', |(c, _)| c),
+                '2'..='8' => (*tile as u8 + 1) as char,
+                _ => '2',
+            };
+        }
+        tiles.fmt(f)
+    }
+}
+
+fn fastest_path(
+    start: Position,
+    end: Position,
+    time: usize,
+    valley: &mut Valley,
+) -> solution::Result<usize> {
+    // States are the time within the period and the position: coming back a
+    // whole period later does not help.
+    let period = valley.states.period();
+    let path = search::bfs(
+        [(time % period, start)],
+        |&(time, position)| {
+            // Stick around, or move around, as long as we do not end up in a
+            // wall or caught in a blizzard.
+            let time = (time + 1) % period;
+            let walls = &valley.walls;
+            let open: Vec<Position> = std::iter::once(position)
+                .chain(walls.neighbours4(position))
+                .filter(|&p| !walls[p])
+                .collect();
+            let blizzards = valley.get_state(time);
+            open.into_iter()
+                .filter(|&p| !blizzards[p])
+                .map(move |p| (time, p))
+                .collect::<Vec<_>>()
+        },
+        |&(_, position)| position == end,
+    );
+
+    path.map(|path| time + path.cost)
+        .ok_or_else(|| "No way through the blizzards.".into())
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 24;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, valley: &Self::Input) -> solution::Result<Self::Part1> {
+        // What is the fewest number of minutes required to avoid the blizzards
+        // and reach the goal?
+        let mut valley = valley.clone();
+        let start = Position::new(1, 0);
+        let end = Position::new(valley.walls.width() - 2, valley.walls.height() - 1);
+        fastest_path(start, end, 0, &mut valley)
+    }
+
+    fn part2(&self, valley: &Self::Input) -> solution::Result<Self::Part2> {
+        // What is the fewest number of minutes required to reach the goal, go
+        // back to the start, then reach the goal again?
+        // Since we can stick around and not move, we can find the shortest
+        // numbers of steps from the start to the end, then use that number of
+        // steps as the starting time at the end for the second leg, then the
+        // shortest to walk again to the end.
+        let mut valley = valley.clone();
+        let start = Position::new(1, 0);
+        let end = Position::new(valley.walls.width() - 2, valley.walls.height() - 1);
+        let time = fastest_path(start, end, 0, &mut valley)?;
+        let time = fastest_path(end, start, time, &mut valley)?;
+        fastest_path(start, end, time, &mut valley)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#
+";
+
+    #[test]
+    fn example() {
+        let valley = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!((valley.walls.width(), valley.walls.height()), (8, 6));
+        assert_eq!(valley.to_string(), EXAMPLE);
+        assert_eq!(Day24.part1(&valley).unwrap(), 18);
+        assert_eq!(Day24.part2(&valley).unwrap(), 54);
+
+        let mut valley = valley;
+        valley.next();
+        assert_eq!(
+            valley.to_string(),
+            "\
+#.######
+#.>3.<.#
+#<..<<.#
+#>2.22.#
+#>v..^<#
+######.#
+"
+        );
+    }
+
+    #[test]
+    fn no_way_through() {
+        let valley = Day24.parse("#.###\n#.#.#\n###.#\n").unwrap();
+        assert!(Day24.part1(&valley).is_err());
+    }
+
+    #[test]
+    fn blizzards_inside() {
+        // Blizzards only blow inside the walls.
+        let error = Day24.parse("#^###\n#...#\n###.#\n").unwrap_err();
+        assert!(error.to_string().contains("line 1, column 2"), "{error}");
+        // There must be a way in and a way out.
+        assert!(Day24.parse("###\n#.#\n#.#\n").is_err());
+        assert!(Day24.parse("#.#\n#.#\n###\n").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Snafu(i64);
+
+impl Snafu {
+    const BASE: i64 = 5;
+}
+
+impl std::ops::Add for Snafu {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Snafu(self.0 + rhs.0)
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut base = 1;
+        let mut result = 0;
+
+        for (idx, c) in s.char_indices().rev() {
+            match c {
+                '=' => result += -2 * base,
+                '-' => result += -base,
+                '0' => (),
+                '1' => result += base,
+                '2' => result += 2 * base,
+                _ => Err(ParseError::new(
+                    s,
+                    &s[idx..],
+                    "a SNAFU digit (=, -, 0, 1 or 2)",
+                ))?,
+            }
+            base *= Self::BASE;
+        }
+
+        Ok(Snafu(result))
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        let mut s = Vec::new();
+        let mut n = self.0;
+        let mut carry = 0;
+        // Keep going while there is a carry, otherwise the leading digit is
+        // lost (3 is "1=", not "=").
+        while n > 0 || carry > 0 {
+            let current = n % Self::BASE + carry;
+            carry = 0;
+            match current {
+                -2 => s.push('='),
+                -1 => s.push('-'),
+                0 => s.push('0'),
+                1 => s.push('1'),
+                2 => s.push('2'),
+                3 => {
+                    s.push('=');
+                    carry = 1;
+                }
+                4 => {
+                    s.push('-');
+                    carry = 1;
+                }
+                5 => {
+                    s.push('0');
+                    carry = 1;
+                }
+                _ => unreachable!("{}", current),
+            }
+            n /= Self::BASE;
+        }
+
+        if s.is_empty() {
+            s.push('0');
+        }
+
+        write!(f, "{}", s.into_iter().rev().collect::<String>())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Snafu>;
+    type Part1 = Snafu;
+    type Part2 = Snafu;
+
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(parse::lines(input).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, snafus: &Self::Input) -> solution::Result<Self::Part1> {
+        // The Elves are starting to get cold. What SNAFU number do you supply
+        // to Bob's console?
+        Ok(snafus
+            .iter()
+            .fold(Snafu(0), |acc, current| Snafu(acc.0 + current.0)))
+    }
+
+    fn part2(&self, _: &Self::Input) -> solution::Result<Self::Part2> {
+        Err("There is no second part on Christmas day.".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122
+";
+
+    #[test]
+    fn conversions() {
+        for (decimal, snafu) in [
+            (0, "0"),
+            (1, "1"),
+            (2, "2"),
+            (3, "1="),
+            (4, "1-"),
+            (5, "10"),
+            (6, "11"),
+            (7, "12"),
+            (8, "2="),
+            (9, "2-"),
+            (10, "20"),
+            (15, "1=0"),
+            (20, "1-0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+        ] {
+            assert_eq!(Snafu(decimal).to_string(), snafu);
+            assert_eq!(snafu.parse::<Snafu>().unwrap().0, decimal);
+        }
+    }
+
+    #[test]
+    fn example() {
+        let snafus = Day25.parse(EXAMPLE).unwrap();
+        let part1 = Day25.part1(&snafus).unwrap();
+        assert_eq!(part1.0, 4890);
+        assert_eq!(part1.to_string(), "2=-1=0");
+    }
+}
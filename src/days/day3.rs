@@ -0,0 +1,134 @@
+use crate::bitset::BitSet;
+use crate::parse::ParseError;
+use crate::solution::{self, Solution};
+
+type Item = u8;
+// Items are ASCII letters, which all fit in two words.
+type Items = BitSet<2>;
+
+fn items(items: &[Item]) -> Items {
+    items.iter().map(|&item| usize::from(item)).collect()
+}
+
+trait Priority {
+    fn priority(self) -> u32;
+}
+
+impl Priority for Item {
+    fn priority(self) -> u32 {
+        u32::from(if self.is_ascii_lowercase() {
+            self - b'a' + 1
+        } else {
+            self - b'A' + 27
+        })
+    }
+}
+
+pub trait RuckSack {
+    fn common_items(&self) -> Items;
+}
+
+impl RuckSack for str {
+    fn common_items(&self) -> Items {
+        let (first, second) = self.as_bytes().split_at(self.len() / 2);
+        items(first) & items(second)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    const DAY: u8 = 3;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        // Elves come in groups of three.
+        let count = input.lines().count();
+        if let Some(line) = input.lines().nth(count - count % 3) {
+            return Err(ParseError::new(input, line, "another two rucksacks").into());
+        }
+        Ok(input
+            .lines()
+            .map(|line| {
+                // Items are letters, split evenly between both compartments.
+                if let Some(idx) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                    Err(ParseError::new(input, &line[idx..], "an item (a letter)"))
+                } else if line.len() % 2 != 0 {
+                    Err(ParseError::new(input, line, "an even number of items"))
+                } else {
+                    Ok(line.to_string())
+                }
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> solution::Result<Self::Part1> {
+        // Find the item type that appears in both compartments of each
+        // rucksack. What is the sum of the priorities of those item types?
+        Ok(rucksacks
+            .iter()
+            .flat_map(|rucksack| rucksack.common_items())
+            .map(|item| (item as Item).priority())
+            .sum())
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> solution::Result<Self::Part2> {
+        // Find the item type that corresponds to the badges of each three-Elf
+        // group. What is the sum of the priorities of those item types?
+        Ok(rucksacks
+            .chunks_exact(3)
+            .flat_map(|group| {
+                group
+                    .iter()
+                    .map(|rucksack| items(rucksack.as_bytes()))
+                    .reduce(|accum, items| accum & items)
+                    .unwrap_or_default()
+            })
+            .map(|item| (item as Item).priority())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn common_items() {
+        let common: Vec<Item> = EXAMPLE
+            .lines()
+            .flat_map(RuckSack::common_items)
+            .map(|item| item as Item)
+            .collect();
+        assert_eq!(common, b"pLPvts");
+        assert_eq!(b'p'.priority(), 16);
+        assert_eq!(b'L'.priority(), 38);
+    }
+
+    #[test]
+    fn example() {
+        let rucksacks = Day3.parse(EXAMPLE).unwrap();
+        assert_eq!(Day3.part1(&rucksacks).unwrap(), 157);
+        assert_eq!(Day3.part2(&rucksacks).unwrap(), 70);
+    }
+
+    #[test]
+    fn groups_of_three() {
+        let four: Vec<&str> = EXAMPLE.lines().take(4).collect();
+        let error = Day3.parse(&four.join("\n")).unwrap_err();
+        assert!(error.to_string().starts_with("line 4,"), "{error}");
+    }
+}
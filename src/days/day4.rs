@@ -0,0 +1,73 @@
+use crate::parse;
+use crate::range_extension::RangeExtension;
+use crate::solution::{self, Solution};
+use std::ops::RangeInclusive;
+
+type SectionId = u32;
+type Assignment = (RangeInclusive<SectionId>, RangeInclusive<SectionId>);
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Assignment>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 4;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        let range = |text| -> Result<RangeInclusive<SectionId>, parse::ParseError> {
+            let (start, end) = parse::split_once(input, text, "-")?;
+            Ok(RangeInclusive::new(
+                parse::parse(input, start, "a section ID")?,
+                parse::parse(input, end, "a section ID")?,
+            ))
+        };
+
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (left, right) = parse::split_once(input, line, ",")?;
+                Ok((range(left)?, range(right)?))
+            })
+            .collect::<Result<_, parse::ParseError>>()?)
+    }
+
+    fn part1(&self, assignments: &Self::Input) -> solution::Result<Self::Part1> {
+        // In how many assignment pairs does one range fully contain the other?
+        Ok(assignments
+            .iter()
+            .filter(|(r0, r1)| r0.contains_range(r1) || r1.contains_range(r0))
+            .count())
+    }
+
+    fn part2(&self, assignments: &Self::Input) -> solution::Result<Self::Part2> {
+        // In how many assignment pairs do the ranges overlap?
+        Ok(assignments
+            .iter()
+            .filter(|(r0, r1)| r0.overlaps(r1))
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn example() {
+        let assignments = Day4.parse(EXAMPLE).unwrap();
+        assert_eq!(Day4.part1(&assignments).unwrap(), 2);
+        assert_eq!(Day4.part2(&assignments).unwrap(), 4);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+use std::str::FromStr;
+
+type Stack = Vec<char>;
+type Stacks = Vec<Stack>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = parse::strip_prefix(s, s, "move ")?;
+        let (count, rest) = parse::split_once(s, rest, " from ")?;
+        let (from, to) = parse::split_once(s, rest, " to ")?;
+
+        // The stacks are 1-indexed.
+        let stack = |text| {
+            parse::parse::<usize>(s, text, "a stack number")?
+                .checked_sub(1)
+                .ok_or_else(|| ParseError::new(s, text, "a stack number"))
+        };
+
+        Ok(Instruction {
+            count: parse::parse(s, count, "a number of crates")?,
+            from: stack(from)?,
+            to: stack(to)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Crane {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+fn move_crates(mut stacks: Stacks, instructions: &[Instruction], crane: Crane) -> Stacks {
+    for &Instruction { count, from, to } in instructions {
+        let idx = stacks[from].len() - count;
+        let mut tail = stacks[from].split_off(idx);
+        match crane {
+            Crane::CrateMover9000 => {
+                tail.reverse();
+            }
+            Crane::CrateMover9001 => (),
+        }
+        stacks[to].append(&mut tail);
+    }
+
+    stacks
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Vec<Instruction>);
+    type Part1 = String;
+    type Part2 = String;
+
+    const DAY: u8 = 5;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        let (stacks, steps) = parse::split_once(input, input, "\n\n")?;
+
+        // Build the stacks. The last (first) line to allocate, the rest to
+        // fill.
+        let mut iter = stacks
+            .lines()
+            .rev()
+            .map(|line| line.chars().skip(1).step_by(4));
+
+        let nbr_stacks = iter
+            .by_ref()
+            .next()
+            .map(Iterator::count)
+            .unwrap_or_default();
+        let mut stacks = vec![Stack::new(); nbr_stacks];
+
+        for line in iter {
+            for (idx, c) in line.enumerate().filter(|(_, c)| c.is_alphabetic()) {
+                stacks[idx].push(c);
+            }
+        }
+
+        // Parse the instructions.
+        let instructions = parse::lines::<Instruction>(steps)
+            .zip(steps.lines())
+            .map(|(instruction, line)| {
+                let instruction = instruction.map_err(|e| e.within(input, steps))?;
+                if instruction.from.max(instruction.to) < nbr_stacks {
+                    Ok(instruction)
+                } else {
+                    Err(ParseError::new(
+                        input,
+                        line,
+                        format!("stacks between 1 and {nbr_stacks}"),
+                    ))
+                }
+            })
+            .collect::<Result<Vec<Instruction>, _>>()?;
+
+        Ok((stacks, instructions))
+    }
+
+    fn part1(&self, (stacks, instructions): &Self::Input) -> solution::Result<Self::Part1> {
+        // After the rearrangement procedure completes, what crate ends up on
+        // top of each stack?
+        Ok(
+            move_crates(stacks.clone(), instructions, Crane::CrateMover9000)
+                .iter()
+                .filter_map(|stack| stack.last())
+                .collect(),
+        )
+    }
+
+    fn part2(&self, (stacks, instructions): &Self::Input) -> solution::Result<Self::Part2> {
+        // After the rearrangement procedure completes, what crate ends up on
+        // top of each stack?
+        Ok(
+            move_crates(stacks.clone(), instructions, Crane::CrateMover9001)
+                .iter()
+                .filter_map(|stack| stack.last())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The leading spaces are significant.
+    const EXAMPLE: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn stacks() {
+        let (stacks, instructions) = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(stacks, [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+        assert_eq!(instructions.len(), 4);
+
+        let stacks = move_crates(stacks, &instructions[..1], Crane::CrateMover9000);
+        assert_eq!(stacks, [vec!['Z', 'N', 'D'], vec!['M', 'C'], vec!['P']]);
+    }
+
+    #[test]
+    fn example() {
+        let input = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(Day5.part1(&input).unwrap(), "CMZ");
+        assert_eq!(Day5.part2(&input).unwrap(), "MCD");
+    }
+}
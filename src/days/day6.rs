@@ -0,0 +1,71 @@
+use crate::solution::{self, Solution};
+
+#[derive(Debug, Clone, Copy)]
+enum Marker {
+    StartOfPacket = 4,
+    StartOfMessage = 14,
+}
+
+fn marker_position(message: &str, marker: Marker) -> Result<usize, &'static str> {
+    message
+        .as_bytes()
+        .windows(marker as usize)
+        .position(|window| (0..window.len()).all(|idx| !window[idx + 1..].contains(&window[idx])))
+        // Add marker size, since we start at that character.
+        .map(|idx| idx + marker as usize)
+        .ok_or("No marker.")
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 6;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, message: &Self::Input) -> solution::Result<Self::Part1> {
+        // How many characters need to be processed before the first
+        // start-of-packet marker is detected?
+        Ok(marker_position(message, Marker::StartOfPacket)?)
+    }
+
+    fn part2(&self, message: &Self::Input) -> solution::Result<Self::Part2> {
+        // How many characters need to be processed before the first
+        // start-of-message marker is detected?
+        Ok(marker_position(message, Marker::StartOfMessage)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn examples() {
+        for (example, part1, part2) in EXAMPLES {
+            let message = Day6.parse(example).unwrap();
+            assert_eq!(Day6.part1(&message).unwrap(), part1, "{example}");
+            assert_eq!(Day6.part2(&message).unwrap(), part2, "{example}");
+        }
+    }
+
+    #[test]
+    fn no_marker() {
+        assert!(marker_position("abcabc", Marker::StartOfPacket).is_err());
+    }
+}
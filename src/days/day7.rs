@@ -0,0 +1,228 @@
+use crate::parse::{self, ParseError};
+use crate::solution::{self, Solution};
+use std::collections::hash_map::Iter;
+use std::collections::HashMap;
+
+const LIMIT: usize = 100000;
+const TOTAL_DISK_SPACE: usize = 70000000;
+const UPDATE_SIZE: usize = 30000000;
+
+type Directory = HashMap<String, Entry>;
+type File = usize;
+
+#[derive(Debug, Clone)]
+pub enum Entry {
+    Directory(Directory),
+    File(File),
+}
+
+impl Entry {
+    pub fn new_directory() -> Self {
+        Entry::Directory(HashMap::new())
+    }
+
+    pub fn new_file(s: usize) -> Self {
+        Entry::File(s)
+    }
+
+    pub fn is_directory(&self) -> bool {
+        matches!(self, Entry::Directory(_))
+    }
+
+    pub fn is_file(&self) -> bool {
+        matches!(self, Entry::File(_))
+    }
+
+    pub fn add_entry(&mut self, name: &str, entry: Entry) {
+        match self {
+            Entry::Directory(h) => h.insert(name.to_string(), entry),
+            Entry::File(_) => panic!("Attempting to add an entry to a file."),
+        };
+    }
+
+    pub fn list(&self) -> Option<Iter<'_, String, Entry>> {
+        match self {
+            Entry::Directory(h) => Some(h.iter()),
+            Entry::File(_) => None,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        match self {
+            Entry::Directory(h) => h.values().map(Entry::size).sum(),
+            Entry::File(size) => *size,
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Entry, ParseError> {
+    fn create_directory<'a>(
+        input: &str,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Entry, ParseError> {
+        let mut current = Entry::new_directory();
+
+        while let Some(line) = lines.next() {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                ["$", "cd", ".."] => break,
+                ["$", "cd", "/"] => {
+                    return Err(ParseError::new(input, words[2], "a subdirectory"));
+                }
+                ["$", "cd", name] => {
+                    let d = create_directory(input, lines)?;
+                    current.add_entry(name, d);
+                }
+                ["$", "ls"] => (),
+                ["$", ..] => return Err(ParseError::new(input, line, "a command")),
+                ["dir", name] => current.add_entry(name, Entry::new_directory()),
+                [size, name] => current.add_entry(
+                    name,
+                    Entry::new_file(parse::parse(input, size, "a file size")?),
+                ),
+                _ => return Err(ParseError::new(input, line, "a command or an entry")),
+            }
+        }
+
+        Ok(current)
+    }
+
+    let mut lines = input.lines();
+    // This is always "$ cd /".
+    match lines.next() {
+        Some("$ cd /") => create_directory(input, &mut lines),
+        line => Err(ParseError::new(input, line.unwrap_or(input), "\"$ cd /\"")),
+    }
+}
+
+fn directory_sizes(root: &Entry) -> Vec<usize> {
+    let mut dir_sizes = Vec::new();
+    let mut to_visit = vec![root];
+    while let Some(current) = to_visit.pop() {
+        dir_sizes.push(current.size());
+
+        if let Some(entries) = current.list() {
+            to_visit
+                .extend(entries.filter_map(|(_, v)| if v.is_directory() { Some(v) } else { None }));
+        }
+    }
+
+    dir_sizes
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day7 {
+    limit: usize,
+    disk_space: usize,
+    update_size: usize,
+}
+
+impl Default for Day7 {
+    fn default() -> Self {
+        Day7 {
+            limit: LIMIT,
+            disk_space: TOTAL_DISK_SPACE,
+            update_size: UPDATE_SIZE,
+        }
+    }
+}
+
+impl Solution for Day7 {
+    type Input = Entry;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 7;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(read_input(input)?)
+    }
+
+    fn part1(&self, root: &Self::Input) -> solution::Result<Self::Part1> {
+        // Find all of the directories with a total size of at most 100000.
+        // What is the sum of the total sizes of those directories?
+        Ok(directory_sizes(root)
+            .iter()
+            .filter(|&&s| s <= self.limit)
+            .sum())
+    }
+
+    fn part2(&self, root: &Self::Input) -> solution::Result<Self::Part2> {
+        // Find the smallest directory that, if deleted, would free up enough
+        // space on the filesystem to run the update. What is the total size of
+        // that directory?
+        let root_size = root.size();
+        Ok(directory_sizes(root)
+            .into_iter()
+            .filter(|&s| self.disk_space + s >= self.update_size + root_size)
+            .min()
+            .ok_or("Not even deleting everything frees up enough space.")?)
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("limit", self.limit.to_string()),
+            ("disk_space", self.disk_space.to_string()),
+            ("update_size", self.update_size.to_string()),
+        ]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> solution::Result<()> {
+        match name {
+            "limit" => self.limit = solution::parameter(name, value)?,
+            "disk_space" => self.disk_space = solution::parameter(name, value)?,
+            "update_size" => self.update_size = solution::parameter(name, value)?,
+            _ => return Err(solution::unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn sizes() {
+        let root = Day7::default().parse(EXAMPLE).unwrap();
+        assert_eq!(root.size(), 48381165);
+
+        let mut sizes = directory_sizes(&root);
+        sizes.sort_unstable();
+        assert_eq!(sizes, [584, 94853, 24933642, 48381165]);
+    }
+
+    #[test]
+    fn example() {
+        let day = Day7::default();
+        let root = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&root).unwrap(), 95437);
+        assert_eq!(day.part2(&root).unwrap(), 24933642);
+    }
+}
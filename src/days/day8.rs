@@ -0,0 +1,169 @@
+use crate::grid::Grid;
+use crate::solution::{self, Solution};
+use std::collections::HashSet;
+
+type Position = (usize, usize);
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 8;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(Grid::parse_with(input, None, |c| {
+            c.to_digit(10).ok_or("a tree height (a digit)")
+        })?)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> solution::Result<Self::Part1> {
+        // Consider your map; how many trees are visible from outside the grid?
+        let row_positions: HashSet<Position> = grid
+            .rows()
+            .enumerate()
+            .flat_map(|(idy, row)| {
+                let row: Vec<u32> = row.copied().collect();
+                // First forward.
+                let mut trees = row.iter().enumerate();
+                let (idx, first) = trees.next().unwrap_or((0, &0));
+
+                let forward = std::iter::once((idy, idx)).chain(trees.scan(
+                    (idx, *first),
+                    move |(idx, top), (current, tree)| {
+                        if tree > top {
+                            *idx = current;
+                            *top = *tree;
+                        }
+                        Some((idy, *idx))
+                    },
+                ));
+
+                // Now backward.
+                let mut trees = row.iter().enumerate().rev();
+                let (idx, first) = trees.next().unwrap_or((0, &0));
+
+                let backward = std::iter::once((idy, idx)).chain(trees.scan(
+                    (idx, *first),
+                    move |(idx, top), (current, tree)| {
+                        if tree > top {
+                            *idx = current;
+                            *top = *tree;
+                        }
+                        Some((idy, *idx))
+                    },
+                ));
+                forward.chain(backward).collect::<HashSet<Position>>()
+            })
+            .collect();
+
+        let col_positions: HashSet<Position> = grid
+            .columns()
+            .enumerate()
+            .flat_map(|(idx, col)| {
+                let col: Vec<u32> = col.copied().collect();
+                // First forward.
+                let mut trees = col.iter().enumerate();
+                let (idy, first) = trees.next().unwrap_or((0, &0));
+
+                let forward = std::iter::once((idy, idx)).chain(trees.scan(
+                    (idy, *first),
+                    move |(idy, top), (current, tree)| {
+                        if tree > top {
+                            *idy = current;
+                            *top = *tree;
+                        }
+                        Some((*idy, idx))
+                    },
+                ));
+
+                // Now backward.
+                let mut trees = col.iter().enumerate().rev();
+                let (idy, first) = trees.next().unwrap_or((0, &0));
+
+                let backward = std::iter::once((idy, idx)).chain(trees.scan(
+                    (idy, *first),
+                    move |(idy, top), (current, tree)| {
+                        if tree > top {
+                            *idy = current;
+                            *top = *tree;
+                        }
+                        Some((*idy, idx))
+                    },
+                ));
+                forward.chain(backward).collect::<HashSet<Position>>()
+            })
+            .collect();
+        let visible_trees: HashSet<Position> = &row_positions | &col_positions;
+        Ok(visible_trees.len())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> solution::Result<Self::Part2> {
+        // Consider each tree on your map. What is the highest scenic score
+        // possible for any tree?
+        Ok(grid
+            .iter()
+            .map(|(p, &tree)| {
+                let (idx, idy) = (p.x(), p.y());
+
+                let row: Vec<u32> = grid.row(idy).copied().collect();
+                let col: Vec<u32> = grid.column(idx).copied().collect();
+
+                // Scenic score.
+                (row.iter()
+                    .take(idx)
+                    .rev()
+                    .position(|&h| h >= tree)
+                    .map_or(idx, |c| c + 1))
+                    * (row
+                        .iter()
+                        .skip(idx + 1)
+                        .position(|&h| h >= tree)
+                        .map_or(grid.width() - idx - 1, |c| c + 1))
+                    * (col
+                        .iter()
+                        .take(idy)
+                        .rev()
+                        .position(|&h| h >= tree)
+                        .map_or(idy, |c| c + 1))
+                    * (col
+                        .iter()
+                        .skip(idy + 1)
+                        .position(|&h| h >= tree)
+                        .map_or(grid.height() - idy - 1, |c| c + 1))
+            })
+            .max()
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn example() {
+        let grid = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(Day8.part1(&grid).unwrap(), 21);
+        assert_eq!(Day8.part2(&grid).unwrap(), 8);
+    }
+
+    #[test]
+    fn not_square() {
+        // The example, without its last two columns.
+        let grid = Day8.parse("303\n255\n653\n335\n353\n").unwrap();
+        assert_eq!(Day8.part1(&grid).unwrap(), 14);
+        assert_eq!(Day8.part2(&grid).unwrap(), 2);
+    }
+}
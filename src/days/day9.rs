@@ -0,0 +1,140 @@
+use crate::parse;
+use crate::position::{self, Direction, Math};
+use crate::solution::{self, Solution};
+use std::collections::HashSet;
+
+type Position = position::Position<isize, Math>;
+type Instruction = (Direction, usize);
+
+fn simulation(instructions: &[Instruction], knots: usize) -> solution::Result<usize> {
+    if knots == 0 {
+        return Err("A rope needs at least one knot.".into());
+    }
+
+    let mut knots = vec![Position::default(); knots];
+    let mut visited = HashSet::new();
+    visited.insert(Position::default());
+
+    for &(direction, steps) in instructions {
+        for _ in 0..steps {
+            knots[0] += direction;
+            for k in 1..knots.len() {
+                let d = knots[k - 1] - knots[k];
+                // Advance towards the preceding knot if too far away.
+                if d.maximum_norm() > 1 {
+                    knots[k] += d.signum();
+                }
+            }
+            visited.insert(*knots.last().expect("At least one knot."));
+        }
+    }
+
+    Ok(visited.len())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day9 {
+    part1_knots: usize,
+    part2_knots: usize,
+}
+
+impl Default for Day9 {
+    fn default() -> Self {
+        Day9 {
+            part1_knots: 2,
+            part2_knots: 10,
+        }
+    }
+}
+
+impl Solution for Day9 {
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 9;
+
+    fn parse(&self, input: &str) -> solution::Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|instruction| {
+                let (direction, steps) = parse::split_once(input, instruction, " ")?;
+                let direction = parse::one_of(input, direction, "UDLR")?;
+                let direction = Direction::try_from(direction).expect("Checked above.");
+                let steps: usize = parse::parse(input, steps, "a number of steps")?;
+                Ok((direction, steps))
+            })
+            .collect::<Result<_, parse::ParseError>>()?)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> solution::Result<Self::Part1> {
+        // Simulate your complete hypothetical series of motions. How many
+        // positions does the tail of the rope visit at least once?
+        simulation(instructions, self.part1_knots)
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> solution::Result<Self::Part2> {
+        // Simulate your complete series of motions on a larger rope with ten
+        // knots. How many positions does the tail of the rope visit at least
+        // once?
+        simulation(instructions, self.part2_knots)
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("part1_knots", self.part1_knots.to_string()),
+            ("part2_knots", self.part2_knots.to_string()),
+        ]
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> solution::Result<()> {
+        match name {
+            "part1_knots" => self.part1_knots = solution::parameter(name, value)?,
+            "part2_knots" => self.part2_knots = solution::parameter(name, value)?,
+            _ => return Err(solution::unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn example() {
+        let day = Day9::default();
+        let instructions = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&instructions).unwrap(), 13);
+        assert_eq!(day.part2(&instructions).unwrap(), 1);
+    }
+
+    #[test]
+    fn larger_example() {
+        let day = Day9::default();
+        let instructions = day.parse(LARGER_EXAMPLE).unwrap();
+        assert_eq!(day.part2(&instructions).unwrap(), 36);
+    }
+}
@@ -0,0 +1,113 @@
+//! Regions of states that can reach each other, whatever the neighbours of a
+//! state are.
+
+use crate::point::{BoundingBox, Point};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Every state that can be reached from `starts`, starts included.
+pub fn flood_fill<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut filled = HashSet::new();
+    let mut to_visit = Vec::new();
+    for start in starts {
+        if filled.insert(start.clone()) {
+            to_visit.push(start);
+        }
+    }
+
+    while let Some(state) = to_visit.pop() {
+        for next in neighbours(&state) {
+            if filled.insert(next.clone()) {
+                to_visit.push(next);
+            }
+        }
+    }
+
+    filled
+}
+
+/// States that can all reach each other.
+#[derive(Debug, Clone)]
+pub struct Component<S> {
+    pub states: HashSet<S>,
+}
+
+impl<S> Component<S> {
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// The smallest box around the component, for states with coordinates.
+    pub fn bounding_box<const N: usize>(&self) -> BoundingBox<N>
+    where
+        S: Copy + Into<Point<N>>,
+    {
+        BoundingBox::of(self.states.iter().map(|&s| s.into())).expect("Not empty.")
+    }
+}
+
+/// The components `states` fall into, in the order of their first state.
+/// `neighbours` should only lead from a state to others that can lead back.
+pub fn connected_components<S, I>(
+    states: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> Vec<Component<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut components = Vec::new();
+    let mut seen = HashSet::new();
+    for state in states {
+        if !seen.contains(&state) {
+            let states = flood_fill([state], &mut neighbours);
+            seen.extend(states.iter().cloned());
+            components.push(Component { states });
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    #[test]
+    fn islands() {
+        let map = "##..#\n#...#\n..#.#\n##...\n"
+            .parse::<Grid<char>>()
+            .unwrap();
+        let land = |&p: &Position| {
+            map.neighbours4(p)
+                .filter(|&n| map[n] == '#')
+                .collect::<Vec<_>>()
+        };
+
+        let filled = flood_fill([Position::new(0, 0)], land);
+        assert_eq!(filled.len(), 3);
+
+        let islands = map.iter().filter(|&(_, &c)| c == '#').map(|(p, _)| p);
+        let components = connected_components(islands, land);
+        let sizes: Vec<usize> = components.iter().map(Component::len).collect();
+        assert_eq!(sizes, [3, 3, 1, 2]);
+        assert_eq!(
+            components[1].bounding_box(),
+            BoundingBox {
+                min: Point::new([4, 0]),
+                max: Point::new([4, 2]),
+            }
+        );
+    }
+}
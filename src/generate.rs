@@ -0,0 +1,972 @@
+//! Random (but valid and solvable) inputs for every day, to stress the
+//! solutions with inputs larger than our own, or simply different from them.
+//!
+//! Each day has its own idea of what the size is (a number of lines, the side
+//! of a grid, etc.), and a default one close to that of the actual puzzle
+//! inputs.
+
+use crate::bitset::BitSet;
+use crate::rng::Rng;
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    pub day: u8,
+    /// Close to the size of the actual puzzle inputs.
+    pub default_size: usize,
+    /// What the size stands for.
+    pub size: &'static str,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+macro_rules! generators {
+    ($($day:literal => $generate:ident($default_size:literal $size:literal)),* $(,)?) => {
+        pub const GENERATORS: &[Generator] = &[
+            $(Generator {
+                day: $day,
+                default_size: $default_size,
+                size: $size,
+                generate: $generate,
+            },)*
+        ];
+    };
+}
+
+generators! {
+    1 => day1(250 "elves"),
+    2 => day2(2500 "rounds"),
+    3 => day3(300 "rucksacks"),
+    4 => day4(1000 "pairs"),
+    5 => day5(500 "moves"),
+    6 => day6(4096 "characters"),
+    7 => day7(200 "directories"),
+    8 => day8(99 "side of the grid"),
+    9 => day9(2000 "motions"),
+    10 => day10(140 "instructions"),
+    11 => day11(8 "monkeys (8 at most)"),
+    12 => day12(160 "width of the heightmap"),
+    13 => day13(150 "pairs of packets"),
+    14 => day14(150 "paths of rock"),
+    15 => day15(25 "sensors"),
+    16 => day16(15 "valves with a flow"),
+    17 => day17(10000 "jets"),
+    18 => day18(2500 "cubes"),
+    19 => day19(30 "blueprints"),
+    20 => day20(5000 "numbers"),
+    21 => day21(2000 "monkeys"),
+    22 => day22(50 "side of the faces"),
+    23 => day23(70 "side of the grove"),
+    24 => day24(120 "width of the valley"),
+    25 => day25(120 "numbers"),
+}
+
+pub fn get(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn word(rng: &mut Rng, letters: &[u8], len: usize) -> String {
+    (0..len).map(|_| char::from(*rng.choose(letters))).collect()
+}
+
+fn day1(rng: &mut Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let items = rng.range(1..=15);
+            lines((0..items).map(|_| rng.range(1000..=60000_u32).to_string()))
+        })
+        .collect();
+    elves.join("\n")
+}
+
+fn day2(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        format!(
+            "{} {}",
+            char::from(*rng.choose(b"ABC")),
+            char::from(*rng.choose(b"XYZ"))
+        )
+    }))
+}
+
+// In each group of three, the badge is only in the first compartment of each
+// rucksack, and each rucksack gets its own letters so that nothing else is
+// common to the group. Of those letters, only one ends up in both
+// compartments.
+fn day3(rng: &mut Rng, size: usize) -> String {
+    let letters: Vec<u8> = LOWERCASE.iter().chain(UPPERCASE).copied().collect();
+
+    lines((0..size.div_ceil(3).max(1)).flat_map(|_| {
+        let mut letters = letters.clone();
+        rng.shuffle(&mut letters);
+        let badge = letters.pop().expect("52 letters.");
+
+        let rucksacks: Vec<String> = letters
+            .chunks_exact(17)
+            .map(|own| {
+                let (common, own) = own.split_first().expect("17 letters.");
+                let (first_only, second_only) = own.split_at(8);
+                let len = rng.range(4..=16);
+
+                let mut first = vec![*common, badge];
+                first.extend((2..len).map(|_| *rng.choose(first_only)));
+                let mut second = vec![*common];
+                second.extend((1..len).map(|_| *rng.choose(second_only)));
+                rng.shuffle(&mut first);
+                rng.shuffle(&mut second);
+
+                first.into_iter().chain(second).map(char::from).collect()
+            })
+            .collect();
+        rucksacks
+    }))
+}
+
+fn day4(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let mut range = || {
+            let start = rng.range(1..=99_u32);
+            (start, rng.range(start..=99))
+        };
+        let (a, b) = range();
+        let (c, d) = range();
+        format!("{a}-{b},{c}-{d}")
+    }))
+}
+
+// Moves never take more crates than there are on a stack.
+fn day5(rng: &mut Rng, size: usize) -> String {
+    const STACKS: usize = 9;
+    let stacks: Vec<Vec<char>> = (0..STACKS)
+        .map(|_| {
+            (0..rng.range(1..=8))
+                .map(|_| char::from(*rng.choose(UPPERCASE)))
+                .collect()
+        })
+        .collect();
+
+    let top = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let mut drawing: Vec<String> = (0..top)
+        .rev()
+        .map(|level| {
+            let crates: Vec<String> = stacks
+                .iter()
+                .map(|stack| stack.get(level).map_or("   ".into(), |c| format!("[{c}]")))
+                .collect();
+            crates.join(" ")
+        })
+        .collect();
+    let numbers: Vec<String> = (1..=STACKS).map(|n| format!(" {n} ")).collect();
+    drawing.push(numbers.join(" "));
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let moves = (0..size).map(|_| {
+        let non_empty: Vec<usize> = (0..STACKS).filter(|&s| heights[s] > 0).collect();
+        let from = *rng.choose(&non_empty);
+        let to = (from + rng.range(1..=STACKS - 1)) % STACKS;
+        let count = rng.range(1..=heights[from].min(10));
+        heights[from] -= count;
+        heights[to] += count;
+        format!("move {count} from {} to {}", from + 1, to + 1)
+    });
+
+    lines(drawing) + "\n" + &lines(moves)
+}
+
+// Three letters cannot make a marker, and thirteen cannot make a message
+// marker: both are found where they are meant to.
+fn day6(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let mut letters = LOWERCASE.to_vec();
+    rng.shuffle(&mut letters);
+
+    let before_packet = rng.range(0..=(size - 14) / 2);
+    let before_message = rng.range(before_packet..=size - 14);
+    let mut message: Vec<u8> = (0..before_packet)
+        .map(|_| *rng.choose(&letters[..3]))
+        .collect();
+    message.extend((before_packet..before_message).map(|_| *rng.choose(&letters[..13])));
+    rng.shuffle(&mut letters);
+    message.extend(&letters[..14]);
+    message.extend((message.len()..size).map(|_| *rng.choose(LOWERCASE)));
+
+    lines([String::from_utf8(message).expect("ASCII letters.")])
+}
+
+#[derive(Debug, Default)]
+struct Directory {
+    names: HashSet<String>,
+    directories: Vec<(String, usize)>,
+    files: Vec<(String, u32)>,
+}
+
+fn day7(rng: &mut Rng, size: usize) -> String {
+    fn name(rng: &mut Rng, directory: &mut Directory, extension: bool) -> String {
+        loop {
+            let len = rng.range(1..=8);
+            let mut name = word(rng, LOWERCASE, len);
+            if extension && rng.chance(0.5) {
+                name = format!("{name}.{}", word(rng, LOWERCASE, 3));
+            }
+            if directory.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    let mut directories = vec![Directory::default()];
+    for idx in 1..size.max(1) {
+        let parent = rng.range(0..=idx - 1);
+        let name = name(rng, &mut directories[parent], false);
+        directories[parent].directories.push((name, idx));
+        directories.push(Directory::default());
+    }
+    for directory in &mut directories {
+        for _ in 0..rng.range(0..=5) {
+            let name = name(rng, directory, true);
+            directory.files.push((name, rng.range(1000..=300000)));
+        }
+    }
+
+    fn list(rng: &mut Rng, directories: &[Directory], idx: usize, terminal: &mut Vec<String>) {
+        let directory = &directories[idx];
+        terminal.push("$ ls".into());
+        let mut entries: Vec<String> = directory
+            .directories
+            .iter()
+            .map(|(name, _)| format!("dir {name}"))
+            .chain(
+                directory
+                    .files
+                    .iter()
+                    .map(|(name, size)| format!("{size} {name}")),
+            )
+            .collect();
+        rng.shuffle(&mut entries);
+        terminal.extend(entries);
+
+        for (name, idx) in &directory.directories {
+            terminal.push(format!("$ cd {name}"));
+            list(rng, directories, *idx, terminal);
+            terminal.push("$ cd ..".into());
+        }
+    }
+
+    let mut terminal = vec!["$ cd /".to_string()];
+    list(rng, &directories, 0, &mut terminal);
+    lines(terminal)
+}
+
+fn day8(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    lines((0..side).map(|_| {
+        (0..side)
+            .map(|_| char::from(*rng.choose(b"0123456789")))
+            .collect()
+    }))
+}
+
+fn day9(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        format!(
+            "{} {}",
+            char::from(*rng.choose(b"UDLR")),
+            rng.range(1..=20_u32)
+        )
+    }))
+}
+
+// At least the 240 cycles needed to draw the whole screen.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let mut cycles = 0;
+    let mut instructions = Vec::new();
+    while cycles < 240 || instructions.len() < size {
+        if rng.chance(0.3) {
+            instructions.push("noop".to_string());
+            cycles += 1;
+        } else {
+            instructions.push(format!("addx {}", rng.range(-20..=20_i32)));
+            cycles += 2;
+        }
+    }
+
+    lines(instructions)
+}
+
+#[derive(Debug)]
+struct Monkey {
+    items: Vec<u64>,
+    // The operand is the old worry level when there is none.
+    operation: (char, Option<u64>),
+    divisor: u64,
+    targets: [usize; 2],
+}
+
+// Divisors are distinct primes, small enough for worry levels (kept below
+// their product) to be squared without overflowing. Monkeys whose worry levels
+// overflow in the 20 rounds with relief are drawn again.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let monkeys = size.clamp(2, 8);
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19];
+
+    loop {
+        rng.shuffle(&mut primes);
+        let mut squared = false;
+        let game: Vec<Monkey> = (0..monkeys)
+            .map(|monkey| {
+                let items = (0..rng.range(1..=8)).map(|_| rng.range(50..=99)).collect();
+                let operation = match rng.range(0..=2) {
+                    0 if !squared => {
+                        squared = true;
+                        ('*', None)
+                    }
+                    0 | 1 => ('*', Some(rng.range(2..=19))),
+                    _ => ('+', Some(rng.range(1..=9))),
+                };
+                let mut targets = (0..monkeys).filter(|&m| m != monkey).collect::<Vec<_>>();
+                rng.shuffle(&mut targets);
+                let if_false = *targets.get(1).unwrap_or(&targets[0]);
+                Monkey {
+                    items,
+                    operation,
+                    divisor: primes[monkey],
+                    targets: [targets[0], if_false],
+                }
+            })
+            .collect();
+
+        if worry_levels_fit(&game) {
+            let blocks: Vec<String> = game
+                .iter()
+                .enumerate()
+                .map(|(idx, monkey)| {
+                    let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+                    let (op, operand) = monkey.operation;
+                    let operand = operand.map_or("old".to_string(), |w| w.to_string());
+                    lines([
+                        format!("Monkey {idx}:"),
+                        format!("  Starting items: {}", items.join(", ")),
+                        format!("  Operation: new = old {op} {operand}"),
+                        format!("  Test: divisible by {}", monkey.divisor),
+                        format!("    If true: throw to monkey {}", monkey.targets[0]),
+                        format!("    If false: throw to monkey {}", monkey.targets[1]),
+                    ])
+                })
+                .collect();
+            return blocks.join("\n");
+        }
+    }
+}
+
+// Whether worry levels, divided by 3 after each inspection, stay within a u64
+// over 20 rounds.
+fn worry_levels_fit(game: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = game.iter().map(|monkey| monkey.items.clone()).collect();
+    for _ in 0..20 {
+        for (idx, monkey) in game.iter().enumerate() {
+            for item in std::mem::take(&mut items[idx]) {
+                let operand = monkey.operation.1.unwrap_or(item);
+                let item = match monkey.operation.0 {
+                    '*' => item.checked_mul(operand),
+                    _ => item.checked_add(operand),
+                };
+                let item = match item {
+                    Some(item) => item / 3,
+                    None => return false,
+                };
+                let target = monkey.targets[usize::from(item % monkey.divisor != 0)];
+                items[target].push(item);
+            }
+        }
+    }
+    true
+}
+
+// A path snakes through every row, never climbing more than one step at a
+// time from S (at its start) to E (at its end).
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(6);
+    let height = (width / 4).max(5);
+    let len = width * height;
+    let mut elevations = vec![b'a'; len];
+
+    let mut elevation = 0_u8;
+    for (idx, step) in (0..len).map(|step| (step, len - 1 - step)) {
+        let needed = 25 - elevation;
+        elevation = if usize::from(needed) >= step || rng.chance(0.5) {
+            (elevation + 1).min(25)
+        } else if rng.chance(0.4) && usize::from(needed) + 4 < step {
+            elevation.saturating_sub(rng.range(1..=3))
+        } else {
+            elevation
+        };
+        if idx == 0 {
+            elevation = 0;
+        }
+
+        let (y, x) = (idx / width, idx % width);
+        let x = if y % 2 == 0 { x } else { width - 1 - x };
+        elevations[y * width + x] = b'a' + elevation;
+        if idx == 0 {
+            elevations[y * width + x] = b'S';
+        } else if idx == len - 1 {
+            elevations[y * width + x] = b'E';
+        }
+    }
+
+    lines(
+        elevations
+            .chunks(width)
+            .map(|row| String::from_utf8(row.to_vec()).expect("ASCII letters.")),
+    )
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.chance(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.range(0..=10_u8).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| lines([packet(rng, 0), packet(rng, 0)]))
+        .collect();
+    pairs.join("\n")
+}
+
+// Rocks stay below the source of the sand (at 500,0), within a cave that
+// grows with the number of paths.
+// Layouts where sand never reaches the abyss are drawn again: both parts would
+// have the same answer.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    let spread = 20 + size as i64 / 3;
+    let depth = 10 + size as i64 / 3;
+
+    loop {
+        let paths: Vec<Vec<(i64, i64)>> = (0..size.max(1))
+            .map(|_| {
+                let mut x = rng.range(500 - spread..=500 + spread);
+                let mut y = rng.range(2..=depth);
+                let mut points = vec![(x, y)];
+                let mut horizontal = rng.chance(0.5);
+                for _ in 0..rng.range(1..=5) {
+                    let len = rng.range(1..=8);
+                    if horizontal {
+                        x = (x + if rng.chance(0.5) { len } else { -len }).max(1);
+                    } else {
+                        y = (y + if rng.chance(0.5) { len } else { -len }).max(2);
+                    }
+                    horizontal = !horizontal;
+                    points.push((x, y));
+                }
+                points
+            })
+            .collect();
+
+        if sand_reaches_abyss(&paths) {
+            return lines(paths.iter().map(|points| {
+                let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+                points.join(" -> ")
+            }));
+        }
+    }
+}
+
+// Whether sand poured from 500,0 falls past the deepest rock before it blocks
+// the source.
+fn sand_reaches_abyss(paths: &[Vec<(i64, i64)>]) -> bool {
+    let mut blocked = HashSet::new();
+    for points in paths {
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            for x in x0.min(x1)..=x0.max(x1) {
+                for y in y0.min(y1)..=y0.max(y1) {
+                    blocked.insert((x, y));
+                }
+            }
+        }
+    }
+    let deepest = blocked.iter().map(|&(_, y)| y).max().unwrap_or_default();
+
+    while !blocked.contains(&(500, 0)) {
+        let (mut x, mut y) = (500, 0);
+        loop {
+            if y > deepest {
+                return true;
+            }
+            match [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&next| !blocked.contains(&(next, y + 1)))
+            {
+                Some(next) => (x, y) = (next, y + 1),
+                None => break,
+            }
+        }
+        blocked.insert((x, y));
+    }
+    false
+}
+
+// The distress beacon is placed first. Four sensors around it, just out of
+// reach, cover everything else; the others never reach it.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    const MAX: i64 = 4_000_000;
+    let (bx, by) = (rng.range(0..=MAX), rng.range(0..=MAX));
+    let distance = |(x0, y0): (i64, i64), (x1, y1): (i64, i64)| (x0 - x1).abs() + (y0 - y1).abs();
+
+    // Sensors at (±k, ±k) from the beacon reach everything but the beacon
+    // within 2k - 1 of it, and so every corner of the search area.
+    let corners = [(0, 0), (0, MAX), (MAX, 0), (MAX, MAX)];
+    let farthest = corners
+        .iter()
+        .map(|&corner| distance(corner, (bx, by)))
+        .max()
+        .unwrap_or_default();
+    let k = farthest / 2 + 1;
+    let mut sensors: Vec<((i64, i64), (i64, i64))> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+        .into_iter()
+        .map(|(sx, sy)| {
+            let sensor = (bx + sx * k, by + sy * k);
+            (sensor, (sensor.0 - sx * (2 * k - 1), sensor.1))
+        })
+        .collect();
+
+    while sensors.len() < size + 4 {
+        let sensor = (
+            rng.range(-MAX / 4..=MAX + MAX / 4),
+            rng.range(-MAX / 4..=MAX + MAX / 4),
+        );
+        let reach = distance(sensor, (bx, by)) - 1;
+        if reach < 1 {
+            continue;
+        }
+        let reach = rng.range((reach / 4).max(1)..=reach);
+        let dx = rng.range(-reach..=reach);
+        let dy = if rng.chance(0.5) {
+            reach - dx.abs()
+        } else {
+            dx.abs() - reach
+        };
+        sensors.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+    }
+    rng.shuffle(&mut sensors);
+
+    lines(sensors.into_iter().map(|((sx, sy), (bx, by))| {
+        format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}")
+    }))
+}
+
+// Three valves without any flow for every one with some, all connected. There
+// are never more working valves than fit in a `BitSet`, however large the size.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let valves = (4 * size).clamp(2, 26 * 26);
+    let mut names = BTreeSet::from(["AA".to_string()]);
+    while names.len() < valves {
+        names.insert(word(rng, UPPERCASE, 2));
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    // AA comes first.
+    rng.shuffle(&mut names[1..]);
+
+    let mut tunnels = vec![BTreeSet::new(); valves];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for valve in 1..valves {
+        connect(valve, rng.range(0..=valve - 1));
+    }
+    for _ in 0..valves / 4 {
+        connect(rng.range(0..=valves - 1), rng.range(0..=valves - 1));
+    }
+
+    let mut flows: Vec<usize> = (1..valves).collect();
+    rng.shuffle(&mut flows);
+    flows.truncate(size.min(BitSet::<1>::CAPACITY));
+
+    let mut report: Vec<String> = (0..valves)
+        .map(|valve| {
+            let flow_rate = if flows.contains(&valve) {
+                rng.range(3..=25_u32)
+            } else {
+                0
+            };
+            let mut neighbours: Vec<&str> =
+                tunnels[valve].iter().map(|&v| names[v].as_str()).collect();
+            rng.shuffle(&mut neighbours);
+            let tunnels = if neighbours.len() == 1 {
+                format!("tunnel leads to valve {}", neighbours[0])
+            } else {
+                format!("tunnels lead to valves {}", neighbours.join(", "))
+            };
+            format!(
+                "Valve {} has flow rate={flow_rate}; {tunnels}",
+                names[valve]
+            )
+        })
+        .collect();
+    rng.shuffle(&mut report);
+
+    lines(report)
+}
+
+fn day17(rng: &mut Rng, size: usize) -> String {
+    lines([(0..size.max(1))
+        .map(|_| char::from(*rng.choose(b"<>")))
+        .collect()])
+}
+
+// A blob of cubes, dense enough to have pockets of air inside.
+fn day18(rng: &mut Rng, size: usize) -> String {
+    let side = ((3 * size) as f64).cbrt().ceil() as i32;
+    let mut cubes = BTreeSet::new();
+    let mut report = Vec::new();
+    while report.len() < size {
+        let cube = (
+            rng.range(0..=side),
+            rng.range(0..=side),
+            rng.range(0..=side),
+        );
+        if cubes.insert(cube) {
+            report.push(format!("{},{},{}", cube.0, cube.1, cube.2));
+        }
+    }
+
+    lines(report)
+}
+
+fn day19(rng: &mut Rng, size: usize) -> String {
+    lines((1..=size.max(1)).map(|id| {
+        format!(
+            "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            rng.range(2..=4_u32),
+            rng.range(2..=4_u32),
+            rng.range(2..=4_u32),
+            rng.range(8..=20_u32),
+            rng.range(2..=4_u32),
+            rng.range(8..=20_u32),
+        )
+    }))
+}
+
+// Numbers repeat, but for the single 0.
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let zero = rng.range(0..=size - 1);
+    lines((0..size).map(|idx| {
+        if idx == zero {
+            return "0".to_string();
+        }
+        let mut n = 0;
+        while n == 0 {
+            n = rng.range(-10000..=10000_i64);
+        }
+        n.to_string()
+    }))
+}
+
+#[derive(Debug, Default)]
+struct Troop {
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Troop {
+    fn monkey(&mut self, rng: &mut Rng, job: String) -> String {
+        let name = loop {
+            let name = word(rng, LOWERCASE, 4);
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                break name;
+            }
+        };
+        self.jobs.push(format!("{name}: {job}"));
+        name
+    }
+
+    // A monkey yelling `value`, possibly after those of a few others.
+    fn yelling(&mut self, rng: &mut Rng, value: i64, budget: &mut usize) -> String {
+        if *budget == 0 || (1..=20).contains(&value) && rng.chance(0.4) {
+            if value >= 1 {
+                return self.monkey(rng, value.to_string());
+            }
+            // Only positive numbers are ever yelled.
+            let a = rng.range(1..=10);
+            let (a, b) = (
+                self.monkey(rng, a.to_string()),
+                self.monkey(rng, (a - value).to_string()),
+            );
+            return self.monkey(rng, format!("{a} - {b}"));
+        }
+
+        *budget -= 1;
+        let divisor = (2..=9).find(|d| value % d == 0 && value != 0);
+        let (a, op, b) = match (rng.range(0..=3), divisor) {
+            (0, Some(divisor)) => (value / divisor, '*', divisor),
+            (1, _) if value.abs() < 1 << 40 => {
+                let divisor = rng.range(2..=5);
+                (value * divisor, '/', divisor)
+            }
+            (2, _) => {
+                let b = rng.range(1..=value.abs().max(2));
+                (value + b, '-', b)
+            }
+            _ => {
+                let b = rng.range(1..=value.abs().max(2));
+                (value - b, '+', b)
+            }
+        };
+        let a = self.yelling(rng, a, budget);
+        let b = self.yelling(rng, b, budget);
+        self.monkey(rng, format!("{a} {op} {b}"))
+    }
+}
+
+// humn's number goes through a chain of monkeys, each applying a single
+// operation with another monkey's number, up to root. Every operation keeps
+// the result a linear function of humn's number (with a coefficient small
+// enough not to overflow for any number), and divisions are exact for the
+// expected number, which the other side of root yells.
+fn day21(rng: &mut Rng, size: usize) -> String {
+    let mut troop = Troop::default();
+    let mut budget = size / 2;
+    let humn = rng.range(1..=1_000_000_000_000_i64);
+    let mut value = humn;
+    let mut current = "humn".to_string();
+    let mut scale = 1;
+
+    for _ in 0..(size / 10).max(1) {
+        let (job, next) = match rng.range(0..=4) {
+            0 if scale <= 8 => {
+                let factor = rng.range(2..=4);
+                scale *= factor;
+                let other = troop.yelling(rng, factor, &mut budget);
+                (format!("{current} * {other}"), value * factor)
+            }
+            1 => match (2..=scale).find(|d| value % d == 0 && scale % d == 0) {
+                Some(divisor) => {
+                    scale /= divisor;
+                    let other = troop.yelling(rng, divisor, &mut budget);
+                    (format!("{current} / {other}"), value / divisor)
+                }
+                None => continue,
+            },
+            2 => {
+                let term = rng.range(1..=1000);
+                let other = troop.yelling(rng, term, &mut budget);
+                (format!("{current} - {other}"), value - term)
+            }
+            3 => {
+                let term = rng.range(1..=1000);
+                let other = troop.yelling(rng, term, &mut budget);
+                (format!("{other} - {current}"), term - value)
+            }
+            _ => {
+                let term = rng.range(1..=1000);
+                let other = troop.yelling(rng, term, &mut budget);
+                (format!("{other} + {current}"), value + term)
+            }
+        };
+        current = troop.monkey(rng, job);
+        value = next;
+    }
+
+    let other = troop.yelling(rng, value, &mut budget);
+    let mut jobs = troop.jobs;
+    jobs.push(format!("root: {current} + {other}"));
+    // The number humn yells for the first part does not matter.
+    jobs.push(format!("humn: {}", rng.range(1..=5000)));
+    rng.shuffle(&mut jobs);
+
+    lines(jobs)
+}
+
+// Faces are laid out as in any of the 11 cube nets, turned and flipped.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+    let n = size.max(1);
+    let (transpose, flip_x, flip_y) = (rng.chance(0.5), rng.chance(0.5), rng.chance(0.5));
+    let mut net: Vec<(usize, usize)> = rng
+        .choose(&NETS)
+        .lines()
+        .enumerate()
+        .flat_map(|(y, row)| row.match_indices('#').map(move |(x, _)| (x, y)))
+        .map(|(x, y)| if transpose { (y, x) } else { (x, y) })
+        .collect();
+    let rows = net.iter().map(|&(_, y)| y + 1).max().unwrap_or_default();
+    let columns = net.iter().map(|&(x, _)| x + 1).max().unwrap_or_default();
+    for (x, y) in net.iter_mut() {
+        if flip_x {
+            *x = columns - 1 - *x;
+        }
+        if flip_y {
+            *y = rows - 1 - *y;
+        }
+    }
+
+    let mut map: Vec<String> = (0..rows * n)
+        .map(|y| {
+            let row: String = (0..columns * n)
+                .map(|x| match net.contains(&(x / n, y / n)) {
+                    true if rng.chance(0.1) => '#',
+                    true => '.',
+                    false => ' ',
+                })
+                .collect();
+            row.trim_end().to_string()
+        })
+        .collect();
+    // Make sure there is somewhere to start from.
+    let start = map[0].find('#').filter(|&x| !map[0][..x].contains('.'));
+    if let Some(x) = start {
+        map[0].replace_range(x..=x, ".");
+    }
+
+    let mut path = rng.range(1..=50_u8).to_string();
+    for _ in 0..40 * n {
+        path.push(*rng.choose(&['L', 'R']));
+        path += &rng.range(1..=50_u8).to_string();
+    }
+
+    lines(map) + "\n" + &lines([path])
+}
+
+fn day23(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    lines((0..side).map(|_| {
+        (0..side)
+            .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+            .collect()
+    }))
+}
+
+// There are no vertical blizzards in the columns of the entrance and the
+// exit, as in the actual input. Blizzards would never move out of the way in
+// a valley only one tile wide (or high).
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(3);
+    let height = (width / 5).max(2);
+
+    let mut valley = vec![format!("#.{}", "#".repeat(width))];
+    valley.extend((0..height).map(|_| {
+        let row: String = (1..=width)
+            .map(|x| {
+                let blizzards: &[char] = if x == 1 || x == width {
+                    &['<', '>']
+                } else {
+                    &['<', '>', '^', 'v']
+                };
+                if rng.chance(0.5) {
+                    *rng.choose(blizzards)
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        format!("#{row}#")
+    }));
+    valley.push(format!("{}.#", "#".repeat(width)));
+
+    lines(valley)
+}
+
+// Numbers start with 1 or 2, so that they are all positive.
+fn day25(rng: &mut Rng, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| {
+        let mut snafu = word(rng, b"12", 1);
+        let len = rng.range(0..=19);
+        snafu += &word(rng, b"=-012", len);
+        snafu
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    // Every generated input can be parsed, with every size and seed.
+    #[test]
+    fn valid() {
+        for generator in GENERATORS {
+            let solution = days::get(generator.day).unwrap().solution();
+            for (seed, size) in [(0, 1), (1, 5), (2, generator.default_size)] {
+                let input = generator.generate(seed, size);
+                if let Err(e) = solution.parse(&input) {
+                    panic!("Day {}, seed {seed}, size {size}: {e}", generator.day);
+                }
+            }
+        }
+    }
+
+    // Small inputs are solved quickly enough, even without optimizations.
+    #[test]
+    fn solvable() {
+        // Day 15 only makes sense with the actual search area, and day 19
+        // takes minutes with its 32 minutes: both are too slow here.
+        for generator in GENERATORS.iter().filter(|g| ![15, 19].contains(&g.day)) {
+            let solution = days::get(generator.day).unwrap().solution();
+            let input = generator.generate(3, 4);
+            let parsed = solution.parse(&input).unwrap();
+            for part in 1..=solution.parts() {
+                if let Err(e) = solution.solve(parsed.as_ref(), part) {
+                    panic!("Day {}, part {part}: {e}\n{input}", generator.day);
+                }
+            }
+        }
+    }
+
+    // Some sand always falls into the abyss, so part 1 stops before part 2.
+    #[test]
+    fn day14_abyss() {
+        let generator = GENERATORS.iter().find(|g| g.day == 14).unwrap();
+        let solution = days::get(14).unwrap().solution();
+        for seed in [1, 3, 4, 7] {
+            let parsed = solution.parse(&generator.generate(seed, 20)).unwrap();
+            let part1 = solution.solve(parsed.as_ref(), 1).unwrap();
+            let part2 = solution.solve(parsed.as_ref(), 2).unwrap();
+            assert_ne!(part1, part2, "Seed {seed}");
+        }
+    }
+
+    #[test]
+    fn day16_working_valves() {
+        let generator = GENERATORS.iter().find(|g| g.day == 16).unwrap();
+        let solution = days::get(16).unwrap().solution();
+        assert!(solution.parse(&generator.generate(1, 70)).is_ok());
+    }
+}
@@ -1,11 +1,21 @@
-#[derive(Debug, Clone)]
-pub struct Grid<T: Copy> {
+use crate::parse::ParseError;
+use crate::position::{self, Orientation, Screen};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+pub use crate::position::Direction;
+
+/// Grids are read from text, with y growing downward.
+pub type Position = position::Position<usize, Screen>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
     height: usize,
     width: usize,
     grid: Vec<T>,
 }
 
-impl<T: Copy> Grid<T> {
+impl<T> Grid<T> {
     pub fn new(height: usize, width: usize, grid: Vec<T>) -> Option<Self> {
         if height * width == grid.len() {
             Some(Grid {
@@ -18,12 +28,95 @@ impl<T: Copy> Grid<T> {
         }
     }
 
-    pub fn get(&self, position: Position) -> Option<&T> {
-        if position.x < self.width {
-            self.grid.get(position.x + position.y * self.width)
-        } else {
-            None
+    /// Parse a map with one cell per character, `cell` telling what a
+    /// character holds, or what was expected instead. Lines shorter than the
+    /// longest are padded with `padding` if there is one; otherwise they must
+    /// all be as long as the first.
+    pub fn parse_with<F, E>(s: &str, padding: Option<T>, mut cell: F) -> Result<Self, ParseError>
+    where
+        T: Clone,
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let lengths = s.lines().map(|line| line.chars().count());
+        let height = lengths.clone().count();
+        let width = match padding {
+            Some(_) => lengths.max(),
+            None => lengths.take(1).next(),
+        }
+        .unwrap_or_default();
+
+        let mut grid = Vec::with_capacity(height * width);
+        for line in s.lines() {
+            for (idx, c) in line.char_indices() {
+                grid.push(cell(c).map_err(|expected| ParseError::new(s, &line[idx..], expected))?);
+            }
+
+            let length = line.chars().count();
+            match &padding {
+                Some(padding) if length <= width => {
+                    grid.extend(std::iter::repeat_n(padding.clone(), width - length));
+                }
+                _ if length == width => (),
+                _ => return Err(ParseError::new(s, line, format!("a row of {width} cells"))),
+            }
         }
+
+        Ok(Grid::new(height, width, grid).expect("Rows are all as wide."))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            grid: self.grid.iter().map(f).collect(),
+        }
+    }
+
+    /// Print the grid one row per line, turning each cell into a character
+    /// with `cell`.
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> Render<'_, T, F> {
+        Render { grid: self, cell }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x() < self.width && position.y() < self.height
+    }
+
+    fn index(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then_some(position.x() + position.y() * self.width)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index(position).map(|idx| &self.grid[idx])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index(position).map(|idx| &mut self.grid[idx])
+    }
+
+    /// Replace the value at `position`, returning the previous one, or
+    /// `None` outside the grid.
+    pub fn set(&mut self, position: Position, value: T) -> Option<T> {
+        self.get_mut(position)
+            .map(|current| std::mem::replace(current, value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let width = self.width;
+        self.grid
+            .iter()
+            .enumerate()
+            .map(move |(idx, value)| (Position::new(idx % width, idx / width), value))
     }
 
     pub fn row(&self, idy: usize) -> impl Iterator<Item = &T> {
@@ -34,7 +127,7 @@ impl<T: Copy> Grid<T> {
         self.grid
             .iter()
             .skip(idx)
-            .step_by(self.height)
+            .step_by(self.width.max(1))
             .take(self.height)
     }
 
@@ -45,112 +138,232 @@ impl<T: Copy> Grid<T> {
     pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
         (0..self.width).map(|idx| self.column(idx))
     }
+
+    /// The neighbours of `position` up, down, left and right, within the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::ORTHOGONAL)
+    }
+
+    /// The neighbours of `position`, diagonals included, within the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::ALL)
+    }
+
+    fn neighbours(
+        &self,
+        position: Position,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Position> + '_ {
+        directions
+            .iter()
+            .filter_map(move |&d| position + d)
+            .filter(|&p| self.contains(p))
+    }
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    x: usize,
-    y: usize,
+/// A rectangle whose opposite edges meet: leaving it on one side comes back
+/// in on the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Torus {
+    origin: Position,
+    width: usize,
+    height: usize,
 }
 
-impl Position {
-    pub fn x(self) -> usize {
-        self.x
+impl Torus {
+    /// The rectangle `width` wide and `height` high, from its top-left
+    /// corner `origin`. Neither side can be empty.
+    pub fn new(origin: Position, width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "Empty torus.");
+        Torus {
+            origin,
+            width,
+            height,
+        }
     }
 
-    pub fn y(self) -> usize {
-        self.y
+    pub fn width(&self) -> usize {
+        self.width
     }
 
-    pub const fn new(x: usize, y: usize) -> Self {
-        Position { x, y }
+    pub fn height(&self) -> usize {
+        self.height
     }
 
-    pub fn neighbours(self) -> impl Iterator<Item = Self> {
-        self.x
-            .checked_sub(1)
-            .map(|x| Position::new(x, self.y))
-            .into_iter()
-            .chain(Some(Position::new(self.x + 1, self.y)).into_iter())
-            .chain(
-                self.y
-                    .checked_sub(1)
-                    .map(|y| Position::new(self.x, y))
-                    .into_iter(),
-            )
-            .chain(Some(Position::new(self.x, self.y + 1)))
+    pub fn contains(&self, position: Position) -> bool {
+        (self.origin.x()..self.origin.x() + self.width).contains(&position.x())
+            && (self.origin.y()..self.origin.y() + self.height).contains(&position.y())
     }
-}
 
-impl std::ops::Add<Direction> for Position {
-    type Output = Option<Self>;
-
-    fn add(self, rhs: Direction) -> Self::Output {
-        match rhs {
-            Direction::Up => self.y.checked_sub(1).map(|y| Position::new(self.x, y)),
-            Direction::Down => Some(Position::new(self.x, self.y + 1)),
-            Direction::Left => self.x.checked_sub(1).map(|x| Position::new(x, self.y)),
-            Direction::Right => Some(Position::new(self.x + 1, self.y)),
-            Direction::UpLeft => self
-                .x
-                .checked_sub(1)
-                .and_then(|x| self.y.checked_sub(1).map(|y| Position::new(x, y))),
-            Direction::DownLeft => self.x.checked_sub(1).map(|x| Position::new(x, self.y + 1)),
-            Direction::UpRight => self.y.checked_sub(1).map(|y| Position::new(self.x + 1, y)),
-            Direction::DownRight => Some(Position::new(self.x + 1, self.y + 1)),
+    /// Where going `steps` times in `direction` from `position` leads. Panics
+    /// unless `position` is on the torus.
+    pub fn advance(&self, position: Position, direction: Direction, steps: usize) -> Position {
+        assert!(self.contains(position), "Off the torus: {position:?}.");
+        let wrap = |start: usize, coordinate: usize, delta: isize, size: usize| {
+            let offset = (coordinate - start) as i128 + delta as i128 * steps as i128;
+            start + offset.rem_euclid(size as i128) as usize
+        };
+        Position::new(
+            wrap(self.origin.x(), position.x(), direction.dx(), self.width),
+            wrap(
+                self.origin.y(),
+                position.y(),
+                Screen::vertical(direction.dy()),
+                self.height,
+            ),
+        )
+    }
+
+    /// After how many steps anything moving straight is back where it
+    /// started, whatever its direction.
+    pub fn period(&self) -> usize {
+        let (mut a, mut b) = (self.width, self.height);
+        while b != 0 {
+            (a, b) = (b, a % b);
         }
+        self.width / a * self.height
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-    UpLeft,
-    DownLeft,
-    UpRight,
-    DownRight,
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
 }
 
-impl Direction {
-    pub fn rotate_left(self) -> Self {
-        match self {
-            Direction::Up => Direction::Left,
-            Direction::Down => Direction::Right,
-            Direction::Left => Direction::Down,
-            Direction::Right => Direction::Up,
-            Direction::UpLeft => Direction::DownLeft,
-            Direction::DownLeft => Direction::DownRight,
-            Direction::UpRight => Direction::UpLeft,
-            Direction::DownRight => Direction::UpRight,
+impl<T, F: Fn(&T) -> char> Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.grid.rows() {
+            let line: String = row.map(&self.cell).collect();
+            writeln!(f, "{line}")?;
         }
+        Ok(())
     }
+}
 
-    pub fn rotate_right(self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-            Direction::Right => Direction::Down,
-            Direction::UpLeft => Direction::UpRight,
-            Direction::DownLeft => Direction::UpLeft,
-            Direction::UpRight => Direction::DownRight,
-            Direction::DownRight => Direction::DownLeft,
-        }
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.render(|&c| c.into()).fmt(f)
+    }
+}
+
+/// Maps of any characters, all rows as long as the first.
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, None, Ok::<_, &str>)
+    }
+}
+
+impl<T> std::ops::Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Outside the grid: {position:?}."))
+    }
+}
+
+impl<T> std::ops::IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Outside the grid: {position:?}."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_square() {
+        // 0 1 2
+        // 3 4 5
+        let mut grid = Grid::new(2, 3, (0..6).collect()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid[Position::new(0, 1)], 3);
+
+        assert_eq!(grid.set(Position::new(1, 1), 7), Some(4));
+        assert_eq!(grid.set(Position::new(1, 2), 7), None);
+        grid[Position::new(2, 0)] += 10;
+        assert_eq!(
+            grid.iter()
+                .map(|(p, &v)| (p.x(), p.y(), v))
+                .collect::<Vec<_>>(),
+            [
+                (0, 0, 0),
+                (1, 0, 1),
+                (2, 0, 12),
+                (0, 1, 3),
+                (1, 1, 7),
+                (2, 1, 5)
+            ]
+        );
+
+        let mut corner: Vec<_> = grid.neighbours4(Position::new(2, 0)).collect();
+        corner.sort_by_key(|p| (p.x(), p.y()));
+        assert_eq!(corner, [Position::new(1, 0), Position::new(2, 1)]);
+        assert_eq!(grid.neighbours8(Position::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Position::new(1, 1)).count(), 5);
     }
 
-    pub fn reverse(self) -> Self {
-        match self {
-            Direction::Up => Direction::Down,
-            Direction::Down => Direction::Up,
-            Direction::Left => Direction::Right,
-            Direction::Right => Direction::Left,
-            Direction::UpLeft => Direction::DownRight,
-            Direction::DownLeft => Direction::UpRight,
-            Direction::UpRight => Direction::DownLeft,
-            Direction::DownRight => Direction::UpLeft,
+    #[test]
+    fn parse_and_render() {
+        let map = "#.#\n.\n##\n";
+        let grid = Grid::parse_with(map, Some(' '), |c| match c {
+            '#' | '.' => Ok(c),
+            _ => Err("# or ."),
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.to_string(), "#.#\n.  \n## \n");
+        assert_eq!(
+            grid.render(|&c| if c == '#' { 'X' } else { c }).to_string(),
+            "X.X\n.  \nXX \n"
+        );
+
+        // Without padding, rows must be as wide as the first one.
+        let error = map.parse::<Grid<char>>().unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
+        let error = Grid::parse_with("#.\n.x", None, |c| match c {
+            '#' | '.' => Ok(c),
+            _ => Err("# or ."),
+        })
+        .unwrap_err();
+        assert_eq!(
+            (error.line(), error.column(), error.expected()),
+            (2, 2, "# or .")
+        );
+    }
+
+    #[test]
+    fn torus() {
+        let torus = Torus::new(Position::new(1, 1), 6, 4);
+        assert_eq!(torus.period(), 12);
+        assert!(torus.contains(Position::new(6, 4)));
+        assert!(!torus.contains(Position::new(7, 4)));
+
+        let p = Position::new(2, 1);
+        assert_eq!(torus.advance(p, Direction::UP, 1), Position::new(2, 4));
+        assert_eq!(torus.advance(p, Direction::LEFT, 2), Position::new(6, 1));
+        assert_eq!(
+            torus.advance(p, Direction::DOWN_RIGHT, 7),
+            Position::new(3, 4)
+        );
+        for d in Direction::ALL {
+            assert_eq!(torus.advance(p, d, torus.period()), p);
+            assert_eq!(torus.advance(torus.advance(p, d, 5), d.reverse(), 5), p);
         }
     }
+
+    #[test]
+    #[should_panic(expected = "Off the torus")]
+    fn off_torus() {
+        let torus = Torus::new(Position::new(1, 1), 6, 4);
+        torus.advance(Position::new(0, 1), Direction::RIGHT, 1);
+    }
 }
@@ -0,0 +1,80 @@
+use crate::answers::ANSWERS_FILE;
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the `dayN.txt` inputs, when none is given explicitly.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+pub const STDIN: &str = "-";
+
+/// The explicit input directory, otherwise `$AOC_INPUT_DIR`, otherwise
+/// `inputs/`.
+pub fn directory(input_dir: Option<&Path>) -> PathBuf {
+    input_dir
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Every input in `dir`, sorted by name: any file but hidden ones and the
+/// answers.
+pub fn files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if entry.file_type()?.is_file() && !name.starts_with('.') && name != ANSWERS_FILE {
+            files.push(entry.path());
+        }
+    }
+    files.sort_unstable();
+
+    Ok(files)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// The same rules apply to every day, in order:
+    /// - an explicit path, or `-` for the standard input;
+    /// - `dayN.txt` in the explicit input directory;
+    /// - `dayN.txt` in `$AOC_INPUT_DIR`;
+    /// - `inputs/dayN.txt`, relative to the current directory.
+    pub fn resolve(day: u8, path: Option<&str>, input_dir: Option<&Path>) -> Self {
+        match path {
+            Some(STDIN) => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => Source::File(directory(input_dir).join(Self::file_name(day))),
+        }
+    }
+
+    pub fn file_name(day: u8) -> String {
+        format!("day{day}.txt")
+    }
+
+    pub fn read(&self) -> std::io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => std::fs::read_to_string(path),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
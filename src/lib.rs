@@ -1,3 +1,19 @@
+pub mod answers;
+pub mod bench;
+pub mod bitset;
+pub mod days;
+pub mod flood;
+pub mod generate;
 pub mod grid;
+pub mod input;
+pub mod json;
+pub mod parallel;
+pub mod parse;
+pub mod periodic;
+pub mod point;
 pub mod position;
 pub mod range_extension;
+pub mod rng;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
@@ -0,0 +1,142 @@
+//! Just enough of a thread pool to solve independent things concurrently.
+
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+thread_local! {
+    // How many threads the current one may keep busy, when it is limited.
+    static BUDGET: Cell<Option<usize>> = const { Cell::new(None) };
+    // The CPU time of the threads that maps run from the current one used.
+    static WORKERS_CPU_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// The CPU time used by the current thread so far, including that of the
+/// threads of every `map` it ran, where the system tells (on Linux).
+pub fn cpu_time() -> Option<Duration> {
+    // The first field is the time spent on a CPU, in nanoseconds.
+    let stats = std::fs::read_to_string("/proc/thread-self/schedstat").ok()?;
+    let nanos = stats.split_whitespace().next()?.parse().ok()?;
+    Some(Duration::from_nanos(nanos) + WORKERS_CPU_TIME.get())
+}
+
+/// The number of threads worth running from the current one: one per core,
+/// unless `limit` (or a `map` this runs in) says otherwise.
+pub fn threads() -> usize {
+    BUDGET
+        .get()
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+// No more than `threads`, nor than the current thread is allowed.
+fn allowed(threads: usize) -> usize {
+    BUDGET
+        .get()
+        .map_or(threads, |budget| threads.min(budget))
+        .max(1)
+}
+
+/// Run `f` with `threads()` at most `threads`.
+pub fn limit<R>(threads: usize, f: impl FnOnce() -> R) -> R {
+    let previous = BUDGET.replace(Some(allowed(threads)));
+    let result = f();
+    BUDGET.set(previous);
+    result
+}
+
+/// Apply `f` to every item on (up to) `threads` threads, which take the next
+/// item as soon as they are done with one. The results are in the same order
+/// as the items. The threads share the budget between them, so that maps
+/// within `f` do not run more threads than asked for.
+pub fn map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let budget = allowed(threads);
+    let threads = budget.min(items.len());
+    if threads <= 1 {
+        return limit(budget, || items.iter().map(f).collect());
+    }
+    let share = budget / threads;
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    BUDGET.set(Some(share));
+                    let mut done = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(idx) else {
+                            break (done, cpu_time());
+                        };
+                        done.push((idx, f(item)));
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            let (done, cpu_time) = worker
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e));
+            let cpu_time = WORKERS_CPU_TIME.get() + cpu_time.unwrap_or_default();
+            WORKERS_CPU_TIME.set(cpu_time);
+            for (idx, result) in done {
+                results[idx] = Some(result);
+            }
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.expect("Every item was taken by a thread."))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordered() {
+        let items: Vec<u64> = (0..100).collect();
+        let squares = map(&items, 4, |&n| {
+            // Make later items finish first.
+            thread::sleep(Duration::from_micros(100 - n));
+            n * n
+        });
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert_eq!(map(&items, 1, |&n| n + 1)[99], 100);
+        assert!(map(&[] as &[u64], 4, |&n| n).is_empty());
+    }
+
+    #[test]
+    fn nested_budget() {
+        let items: Vec<usize> = (0..6).collect();
+        assert_eq!(map(&items, 3, |_| threads()), [1; 6]);
+        assert_eq!(map(&items[..2], 5, |_| threads()), [2; 2]);
+        assert_eq!(map(&items[..1], 4, |_| threads()), [4]);
+        assert_eq!(limit(1, || map(&items, threads(), |_| threads())), [1; 6]);
+    }
+
+    #[test]
+    fn workers_cpu_time() {
+        let Some(start) = cpu_time() else {
+            return;
+        };
+        // Busy workers count towards the thread that waits for them.
+        let busy = |_: &u8| {
+            let start = cpu_time().unwrap();
+            while cpu_time().unwrap() - start < Duration::from_millis(20) {}
+        };
+        map(&[0; 4], 4, busy);
+        assert!(cpu_time().unwrap() - start >= Duration::from_millis(80));
+    }
+}
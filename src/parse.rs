@@ -0,0 +1,155 @@
+//! A single error type for every parser, so that a malformed input points at
+//! what was wrong with it (instead of panicking or silently becoming zero).
+//!
+//! Positions are found from the offending text itself, which must be a slice
+//! of the string being parsed (as returned by `lines`, `split_once`, `trim`,
+//! etc.).
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<u8>,
+    line: usize,
+    column: usize,
+    text: String,
+    expected: String,
+}
+
+// Line and column (both 1-based) of `text` within `source`, or the start of
+// `source` if `text` is not a slice of it.
+fn position(source: &str, text: &str) -> (usize, usize) {
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|&offset| offset <= source.len() && source.is_char_boundary(offset))
+        .unwrap_or_default();
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl ParseError {
+    pub fn new(source: &str, text: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(source, text);
+        ParseError {
+            day: None,
+            line,
+            column,
+            // Only the line where things went wrong is of interest.
+            text: text.lines().next().unwrap_or_default().to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Move an error found while parsing `inner` to its position in `outer`,
+    /// `inner` being a slice of `outer`.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let (line, column) = position(outer, inner);
+        ParseError {
+            line: self.line + line - 1,
+            column: if self.line == 1 {
+                self.column + column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+// The day is left out, as the runner already starts its messages with it.
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "found nothing.")
+        } else {
+            write!(f, "found {:?}.", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `text` (a slice of `source`) as a `T`, such as a number.
+pub fn parse<T: FromStr>(source: &str, text: &str, expected: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(source, text, expected))
+}
+
+/// Parse every line of `source` as a `T`, reporting errors at their position
+/// in `source`.
+pub fn lines<'a, T>(source: &'a str) -> impl Iterator<Item = Result<T, ParseError>> + 'a
+where
+    T: FromStr<Err = ParseError> + 'a,
+{
+    source
+        .lines()
+        .map(move |line| line.parse().map_err(|e: ParseError| e.within(source, line)))
+}
+
+pub fn split_once<'a>(
+    source: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(source, text, format!("{delimiter:?}")))
+}
+
+pub fn strip_prefix<'a>(source: &str, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(source, text, format!("{prefix:?}")))
+}
+
+/// A single character, among those of `chars`.
+pub fn one_of(source: &str, text: &str, chars: &str) -> Result<char, ParseError> {
+    let mut iter = text.chars();
+    match (iter.next(), iter.next()) {
+        (Some(c), None) if chars.contains(c) => Ok(c),
+        _ => {
+            let chars: Vec<String> = chars.chars().map(String::from).collect();
+            Err(ParseError::new(
+                source,
+                text,
+                format!("one of {}", chars.join(", ")),
+            ))
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Things that come back to the same state at regular intervals.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+/// The states of something that repeats every `period` steps, each computed
+/// once and kept: only one period is ever stored, however far in time.
+#[derive(Debug, Clone)]
+pub struct PeriodicCache<T> {
+    states: Vec<Option<T>>,
+}
+
+impl<T> PeriodicCache<T> {
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Empty period.");
+        PeriodicCache {
+            states: std::iter::repeat_with(|| None).take(period).collect(),
+        }
+    }
+
+    pub fn period(&self) -> usize {
+        self.states.len()
+    }
+
+    /// The state at `time`, computed with `state` (given the time within the
+    /// period) unless it already was.
+    pub fn get_or_insert_with(&mut self, time: usize, state: impl FnOnce(usize) -> T) -> &T {
+        let time = time % self.period();
+        self.states[time].get_or_insert_with(|| state(time))
+    }
+}
+
+/// After `start` steps, a simulation comes back to the same state every
+/// `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step in the same state as `steps`.
+    pub fn earliest(self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+
+    /// What `values` (one per step, at least up to `start + length`) would be
+    /// at `steps`, given that they change by the same amount over each cycle.
+    pub fn extrapolate<M>(self, values: &[M], steps: usize) -> M
+    where
+        M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<usize>,
+    {
+        if steps < self.start {
+            return values[steps];
+        }
+        let repeats = M::try_from((steps - self.start) / self.length)
+            .unwrap_or_else(|_| panic!("Too many repeats."));
+        let growth = values[self.start + self.length] - values[self.start];
+        values[self.earliest(steps)] + repeats * growth
+    }
+}
+
+/// Run `step` on `state` until the `key` of a state was seen before, or
+/// `limit` steps were taken, calling `visit` with every state on the way.
+fn run<S, K: Eq + Hash>(
+    state: &mut S,
+    limit: Option<usize>,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut visit: impl FnMut(&S),
+) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    for steps in 0.. {
+        visit(state);
+        if limit == Some(steps) {
+            break;
+        }
+        if let Some(start) = seen.insert(key(state), steps) {
+            return Some(Cycle {
+                start,
+                length: steps - start,
+            });
+        }
+        step(state);
+    }
+    None
+}
+
+/// Where `step` starts going round in circles from `state`, states being told
+/// apart by their `key`. This never ends if no key ever repeats.
+pub fn find_cycle<S, K: Eq + Hash>(
+    mut state: S,
+    step: impl FnMut(&mut S),
+    key: impl FnMut(&S) -> K,
+) -> Cycle {
+    run(&mut state, None, step, key, |_| ()).expect("No limit.")
+}
+
+/// The `metric` of `state` after `steps` steps, simulating only until it
+/// starts repeating. The metric must change by the same amount over each
+/// cycle.
+pub fn fast_forward<S, K, M>(
+    mut state: S,
+    steps: usize,
+    step: impl FnMut(&mut S),
+    key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+) -> M
+where
+    K: Eq + Hash,
+    M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<usize>,
+{
+    let mut values = Vec::new();
+    let cycle = run(&mut state, Some(steps), step, key, |s| {
+        values.push(metric(s))
+    });
+    match cycle {
+        Some(cycle) => cycle.extrapolate(&values, steps),
+        None => values[steps],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computed_once() {
+        let mut cache = PeriodicCache::new(3);
+        let mut computed = Vec::new();
+        for time in [0, 4, 1, 3, 7, 1000] {
+            cache.get_or_insert_with(time, |t| {
+                computed.push(t);
+                t * 10
+            });
+        }
+        assert_eq!(computed, [0, 1]);
+        assert_eq!(*cache.get_or_insert_with(5, |t| t * 10), 20);
+        assert_eq!(*cache.get_or_insert_with(9, |_| unreachable!()), 0);
+    }
+
+    // Squares modulo some prime, with their running total.
+    fn squares(state: &mut (u64, u64)) {
+        state.0 = (state.0 * state.0 + 1) % 97;
+        state.1 += state.0;
+    }
+
+    #[test]
+    fn cycles() {
+        let cycle = find_cycle(2, |x| *x = (*x * *x + 1) % 97, |&x| x);
+        let mut seen = vec![2];
+        while seen.len() < 200 {
+            let x = seen[seen.len() - 1];
+            seen.push((x * x + 1) % 97);
+        }
+        assert!(cycle.length > 0);
+        assert_eq!(seen[cycle.start], seen[cycle.start + cycle.length]);
+        assert!(cycle.start == 0 || seen[cycle.start - 1] != seen[cycle.start + cycle.length - 1]);
+        for steps in 0..200 {
+            assert_eq!(seen[cycle.earliest(steps)], seen[steps]);
+        }
+
+        let mut state = (2, 0);
+        for steps in 0..300 {
+            assert_eq!(
+                fast_forward((2, 0), steps, squares, |s| s.0, |s| s.1),
+                state.1
+            );
+            squares(&mut state);
+        }
+    }
+}
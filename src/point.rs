@@ -0,0 +1,260 @@
+//! Points with any number of dimensions, for puzzles that leave the plane.
+
+use crate::parse::{self, ParseError};
+use crate::position::{Coordinate, Orientation, Position};
+use std::ops::{Add, AddAssign, Index, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<const N: usize>([isize; N]);
+
+impl<const N: usize> Point<N> {
+    pub const ORIGIN: Self = Point([0; N]);
+
+    pub const fn new(coordinates: [isize; N]) -> Self {
+        Point(coordinates)
+    }
+
+    pub const fn coordinates(self) -> [isize; N] {
+        self.0
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> isize {
+        (self - other).0.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> isize {
+        (self - other)
+            .0
+            .iter()
+            .map(|c| c.abs())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// The 2N points one step away along a single axis.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |axis| {
+            [-1, 1].map(|step| {
+                let mut neighbour = self;
+                neighbour.0[axis] += step;
+                neighbour
+            })
+        })
+    }
+
+    /// The 3^N - 1 points around this one, diagonals included.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        (0..3_usize.pow(N as u32)).filter_map(move |mut idx| {
+            let mut neighbour = self;
+            for c in neighbour.0.iter_mut() {
+                *c += (idx % 3) as isize - 1;
+                idx /= 3;
+            }
+            (neighbour != self).then_some(neighbour)
+        })
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::ORIGIN
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(c, d)| *c += d);
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(c, d)| *c -= d);
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<isize> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Point(self.0.map(|c| c * rhs))
+    }
+}
+
+impl<T: Coordinate, O: Orientation> From<Position<T, O>> for Point<2> {
+    fn from(position: Position<T, O>) -> Self {
+        Point([
+            position.x().offset_from(T::default()),
+            position.y().offset_from(T::default()),
+        ])
+    }
+}
+
+/// Coordinates separated by commas, such as `1,-2,3`.
+impl<const N: usize> FromStr for Point<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coordinates = [0; N];
+        let mut parts = s.split(',');
+        for c in coordinates.iter_mut() {
+            let part = parts
+                .next()
+                .ok_or_else(|| ParseError::new(s, &s[s.len()..], format!("{N} coordinates")))?;
+            *c = parse::parse(s, part.trim(), "a coordinate")?;
+        }
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::new(s, extra, format!("only {N} coordinates")));
+        }
+
+        Ok(Point(coordinates))
+    }
+}
+
+/// The smallest box holding some points, bounds included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> BoundingBox<N> {
+    /// The box around `points`, unless there are none.
+    pub fn of(points: impl IntoIterator<Item = Point<N>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |mut bounds, p| {
+                for axis in 0..N {
+                    bounds.min.0[axis] = bounds.min.0[axis].min(p.0[axis]);
+                    bounds.max.0[axis] = bounds.max.0[axis].max(p.0[axis]);
+                }
+                bounds
+            },
+        ))
+    }
+
+    /// The same box, `margin` larger on every side.
+    pub fn grow(self, margin: isize) -> Self {
+        BoundingBox {
+            min: Point(self.min.0.map(|c| c - margin)),
+            max: Point(self.max.0.map(|c| c + margin)),
+        }
+    }
+
+    pub fn contains(&self, point: Point<N>) -> bool {
+        (0..N).all(|axis| (self.min.0[axis]..=self.max.0[axis]).contains(&point.0[axis]))
+    }
+
+    /// How many points the box holds.
+    pub fn volume(&self) -> usize {
+        (0..N)
+            .map(|axis| (self.max.0[axis] - self.min.0[axis] + 1) as usize)
+            .product()
+    }
+
+    /// Every point in the box, the first axis changing the fastest.
+    pub fn points(self) -> impl Iterator<Item = Point<N>> {
+        (0..self.volume()).map(move |mut idx| {
+            let mut point = self.min;
+            for axis in 0..N {
+                let size = (self.max.0[axis] - self.min.0[axis] + 1) as usize;
+                point.0[axis] += (idx % size) as isize;
+                idx /= size;
+            }
+            point
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours() {
+        let p = Point::new([1, -1, 0, 2]);
+        assert_eq!(p.orthogonal_neighbours().count(), 8);
+        assert!(p
+            .orthogonal_neighbours()
+            .all(|n| n.manhattan_distance(p) == 1));
+        assert_eq!(p.neighbours().count(), 80);
+        assert!(p.neighbours().all(|n| n.chebyshev_distance(p) == 1));
+        assert_eq!(
+            Point::new([5]).neighbours().collect::<Vec<_>>(),
+            [Point::new([4]), Point::new([6])]
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Point::new([1, 2, 3]), Point::new([-2, 0, 5]));
+        assert_eq!(a + b, Point::new([-1, 2, 8]));
+        assert_eq!(a - b, Point::new([3, 2, -2]));
+        assert_eq!(-a * 2, Point::new([-2, -4, -6]));
+        assert_eq!((a.manhattan_distance(b), a.chebyshev_distance(b)), (7, 3));
+        assert_eq!("1, 2,3".parse(), Ok(a));
+        assert!("1,2".parse::<Point<3>>().is_err());
+        assert!("1,2,3,4".parse::<Point<3>>().is_err());
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point::new([1, 5]), Point::new([-2, 3]), Point::new([0, 7])];
+        let bounds = BoundingBox::of(points).unwrap();
+        assert_eq!(bounds.min, Point::new([-2, 3]));
+        assert_eq!(bounds.max, Point::new([1, 7]));
+        assert!(points.into_iter().all(|p| bounds.contains(p)));
+        assert!(!bounds.contains(Point::new([2, 5])));
+        assert!(bounds.grow(1).contains(Point::new([2, 8])));
+        assert_eq!(BoundingBox::<2>::of([]), None);
+
+        assert_eq!(bounds.volume(), 20);
+        let inside: Vec<_> = bounds.points().collect();
+        assert_eq!(inside.len(), 20);
+        assert_eq!(
+            (inside[0], inside[1], inside[19]),
+            (bounds.min, Point::new([-1, 3]), bounds.max)
+        );
+        assert!(inside.iter().all(|&p| bounds.contains(p)));
+    }
+}
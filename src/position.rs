@@ -1,63 +1,202 @@
+//! Positions on a plane, and the directions to move between them.
+//!
+//! Directions are always given as if y grew upward: going `UP` is `(0, 1)`.
+//! Positions tell how their y axis is oriented, so that going up decreases y
+//! on a `Screen` (maps read from text) and increases it in `Math`.
+
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+pub trait Orientation: Copy + Ord + Hash + Default + Debug {
+    /// The change in y when going up.
+    const UP: isize;
+
+    /// The change in y when going `up` steps up, and back.
+    fn vertical(up: isize) -> isize {
+        up * Self::UP
+    }
+}
+
+/// y grows downward, as in text read line by line.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Position {
-    x: isize,
-    y: isize,
+pub struct Screen;
+
+impl Orientation for Screen {
+    const UP: isize = -1;
 }
 
-impl Position {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Position { x, y }
+/// y grows upward.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Math;
+
+impl Orientation for Math {
+    const UP: isize = 1;
+}
+
+/// Integers that can be used as coordinates.
+pub trait Coordinate: Copy + Ord + Hash + Default + Debug {
+    /// `self + delta`, unless it does not fit.
+    fn checked_offset(self, delta: isize) -> Option<Self>;
+    /// `self - origin`, which must fit an `isize`.
+    fn offset_from(self, origin: Self) -> isize {
+        isize::try_from(self.wide_offset_from(origin)).expect("Offset overflow.")
+    }
+    /// `self - origin`, which always fits an `i128`.
+    fn wide_offset_from(self, origin: Self) -> i128;
+    /// `|self - other|`, which must fit a `Self` too.
+    fn distance(self, other: Self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            fn checked_offset(self, delta: isize) -> Option<Self> {
+                Self::try_from(i128::from(self) + delta as i128).ok()
+            }
+
+            fn wide_offset_from(self, origin: Self) -> i128 {
+                i128::from(self) - i128::from(origin)
+            }
+
+            fn distance(self, other: Self) -> Self {
+                Self::try_from(self.abs_diff(other)).expect("Distance overflow.")
+            }
+        })*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+// i128 does not implement From<isize> or From<usize>.
+impl Coordinate for isize {
+    fn checked_offset(self, delta: isize) -> Option<Self> {
+        self.checked_add(delta)
     }
 
-    pub const fn x(self) -> isize {
+    fn wide_offset_from(self, origin: Self) -> i128 {
+        self as i128 - origin as i128
+    }
+
+    fn distance(self, other: Self) -> Self {
+        Self::try_from(self.abs_diff(other)).expect("Distance overflow.")
+    }
+}
+
+impl Coordinate for usize {
+    fn checked_offset(self, delta: isize) -> Option<Self> {
+        self.checked_add_signed(delta)
+    }
+
+    fn wide_offset_from(self, origin: Self) -> i128 {
+        self as i128 - origin as i128
+    }
+
+    fn distance(self, other: Self) -> Self {
+        self.abs_diff(other)
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position<T, O> {
+    x: T,
+    y: T,
+    orientation: PhantomData<O>,
+}
+
+impl<T: Coordinate, O: Orientation> Position<T, O> {
+    pub const fn new(x: T, y: T) -> Self {
+        Position {
+            x,
+            y,
+            orientation: PhantomData,
+        }
+    }
+
+    pub const fn x(self) -> T {
         self.x
     }
 
-    pub const fn y(self) -> isize {
+    pub const fn y(self) -> T {
         self.y
     }
 
-    pub const fn manhattan_distance(self, other: Self) -> isize {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
+    pub fn manhattan_distance(self, other: Self) -> T
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        self.x.distance(other.x) + self.y.distance(other.y)
     }
-}
 
-impl std::ops::Add<Direction> for Position {
-    type Output = Self;
+    /// Move in `direction`, unless the new position does not fit a `T`.
+    pub fn checked_add(self, direction: Direction) -> Option<Self> {
+        Some(Position::new(
+            self.x.checked_offset(direction.dx)?,
+            self.y.checked_offset(O::vertical(direction.dy))?,
+        ))
+    }
 
-    fn add(self, rhs: Direction) -> Self::Output {
-        Position::new(self.x + rhs.dx, self.y + rhs.dy)
+    /// The same position with another type of coordinates, if it fits.
+    pub fn try_cast<U>(self) -> Option<Position<U, O>>
+    where
+        U: Coordinate + TryFrom<T>,
+    {
+        Some(Position::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
     }
 }
 
-impl std::ops::AddAssign<Direction> for Position {
+impl<T: Debug, O> Debug for Position<T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({:?}, {:?})", self.x, self.y)
+    }
+}
+
+// Positions with signed coordinates are expected to stay in range, while the
+// others may well step below 0.
+macro_rules! add_direction {
+    (signed: $($t:ty),*) => {
+        $(impl<O: Orientation> std::ops::Add<Direction> for Position<$t, O> {
+            type Output = Self;
+
+            fn add(self, rhs: Direction) -> Self::Output {
+                self.checked_add(rhs).expect("Overflow.")
+            }
+        })*
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl<O: Orientation> std::ops::Add<Direction> for Position<$t, O> {
+            type Output = Option<Self>;
+
+            fn add(self, rhs: Direction) -> Self::Output {
+                self.checked_add(rhs)
+            }
+        })*
+    };
+}
+
+add_direction!(signed: i8, i16, i32, i64, isize);
+add_direction!(unsigned: u8, u16, u32, u64, usize);
+
+impl<O: Orientation> std::ops::AddAssign<Direction> for Position<isize, O> {
     fn add_assign(&mut self, rhs: Direction) {
-        self.x += rhs.dx;
-        self.y += rhs.dy;
+        *self = *self + rhs;
     }
 }
 
-impl std::ops::Sub<Position> for Position {
+impl<T: Coordinate, O: Orientation> std::ops::Sub for Position<T, O> {
     type Output = Direction;
 
-    fn sub(self, rhs: Position) -> Self::Output {
-        Direction::new(self.x - rhs.x, self.y - rhs.y)
+    fn sub(self, rhs: Self) -> Self::Output {
+        Direction::new(
+            self.x.offset_from(rhs.x),
+            O::vertical(self.y.offset_from(rhs.y)),
+        )
     }
 }
 
-// DOWN and UP are reversed, for reasons...
-pub const UP: Direction = Direction { dx: 0, dy: 1 };
-pub const DOWN: Direction = Direction { dx: 0, dy: -1 };
-pub const LEFT: Direction = Direction { dx: -1, dy: 0 };
-pub const RIGHT: Direction = Direction { dx: 1, dy: 0 };
-pub const UP_LEFT: Direction = Direction { dx: -1, dy: 1 };
-pub const UP_RIGHT: Direction = Direction { dx: 1, dy: 1 };
-pub const DOWN_LEFT: Direction = Direction { dx: -1, dy: -1 };
-pub const DOWN_RIGHT: Direction = Direction { dx: 1, dy: -1 };
-pub const DIRECTIONS: [Direction; 8] = [
-    UP, DOWN, LEFT, RIGHT, UP_LEFT, UP_RIGHT, DOWN_LEFT, DOWN_RIGHT,
-];
-
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Direction {
     dx: isize,
@@ -65,10 +204,43 @@ pub struct Direction {
 }
 
 impl Direction {
+    pub const UP: Direction = Direction::new(0, 1);
+    pub const DOWN: Direction = Direction::new(0, -1);
+    pub const LEFT: Direction = Direction::new(-1, 0);
+    pub const RIGHT: Direction = Direction::new(1, 0);
+    pub const UP_LEFT: Direction = Direction::new(-1, 1);
+    pub const UP_RIGHT: Direction = Direction::new(1, 1);
+    pub const DOWN_LEFT: Direction = Direction::new(-1, -1);
+    pub const DOWN_RIGHT: Direction = Direction::new(1, -1);
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::UP,
+        Direction::DOWN,
+        Direction::LEFT,
+        Direction::RIGHT,
+    ];
+    pub const ALL: [Direction; 8] = [
+        Direction::UP,
+        Direction::DOWN,
+        Direction::LEFT,
+        Direction::RIGHT,
+        Direction::UP_LEFT,
+        Direction::UP_RIGHT,
+        Direction::DOWN_LEFT,
+        Direction::DOWN_RIGHT,
+    ];
+
     pub const fn new(dx: isize, dy: isize) -> Self {
         Direction { dx, dy }
     }
 
+    pub const fn dx(self) -> isize {
+        self.dx
+    }
+
+    pub const fn dy(self) -> isize {
+        self.dy
+    }
+
     pub const fn signum(self) -> Self {
         Direction::new(self.dx.signum(), self.dy.signum())
     }
@@ -76,6 +248,20 @@ impl Direction {
     pub fn maximum_norm(self) -> isize {
         self.dx.abs().max(self.dy.abs())
     }
+
+    /// A quarter turn counterclockwise.
+    pub const fn rotate_left(self) -> Self {
+        Direction::new(-self.dy, self.dx)
+    }
+
+    /// A quarter turn clockwise.
+    pub const fn rotate_right(self) -> Self {
+        Direction::new(self.dy, -self.dx)
+    }
+
+    pub const fn reverse(self) -> Self {
+        Direction::new(-self.dx, -self.dy)
+    }
 }
 
 impl TryFrom<char> for Direction {
@@ -83,11 +269,77 @@ impl TryFrom<char> for Direction {
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'U' => Ok(UP),
-            'D' => Ok(DOWN),
-            'L' => Ok(LEFT),
-            'R' => Ok(RIGHT),
+            'U' => Ok(Direction::UP),
+            'D' => Ok(Direction::DOWN),
+            'L' => Ok(Direction::LEFT),
+            'R' => Ok(Direction::RIGHT),
             _ => Err(value),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientation() {
+        let screen: Position<usize, Screen> = Position::new(1, 1);
+        assert_eq!(screen + Direction::UP, Some(Position::new(1, 0)));
+        assert_eq!(screen + Direction::DOWN_LEFT, Some(Position::new(0, 2)));
+        assert_eq!(Position::<usize, Screen>::new(0, 0) + Direction::UP, None);
+
+        let math: Position<isize, Math> = Position::new(1, 1);
+        assert_eq!(math + Direction::UP, Position::new(1, 2));
+        assert_eq!(math + Direction::DOWN_LEFT, Position::new(0, 0));
+        assert_eq!(math - Position::new(0, 0), Direction::UP_RIGHT);
+        assert_eq!(screen - Position::new(0, 0), Direction::DOWN_RIGHT);
+    }
+
+    #[test]
+    fn conversions() {
+        let signed: Position<isize, Screen> = Position::new(3, -1);
+        assert_eq!(signed.try_cast::<usize>(), None);
+        let signed = signed + Direction::DOWN;
+        let unsigned = signed.try_cast::<usize>().unwrap();
+        assert_eq!(unsigned, Position::new(3, 0));
+        assert_eq!(unsigned.try_cast::<isize>(), Some(signed));
+        assert_eq!(unsigned.manhattan_distance(Position::new(1, 2)), 4);
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(i8::MIN.distance(-1), i8::MAX);
+        assert_eq!(u8::MIN.distance(u8::MAX), u8::MAX);
+        assert_eq!(isize::MAX.distance(0), isize::MAX);
+        assert_eq!(
+            Position::<i8, Screen>::new(-100, 27).manhattan_distance(Position::new(0, 0)),
+            127
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Distance overflow.")]
+    fn distance_overflow() {
+        i8::MIN.distance(i8::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset overflow.")]
+    fn offset_overflow() {
+        u64::MAX.offset_from(0);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::UP.rotate_left(), Direction::LEFT);
+        assert_eq!(Direction::UP.rotate_right(), Direction::RIGHT);
+        assert_eq!(Direction::UP_LEFT.rotate_left(), Direction::DOWN_LEFT);
+        assert_eq!(Direction::DOWN_RIGHT.rotate_right(), Direction::DOWN_LEFT);
+        assert_eq!(Direction::LEFT.reverse(), Direction::RIGHT);
+        for d in Direction::ALL {
+            assert_eq!(d.rotate_left().rotate_right(), d);
+            assert_eq!(d.rotate_left().rotate_left(), d.reverse());
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use std::ops::RangeInclusive;
+use crate::position::Coordinate;
+use std::ops::{Range, RangeInclusive};
 
 pub trait RangeExtension {
     fn contains_range(&self, other: &Self) -> bool;
@@ -6,11 +7,269 @@ pub trait RangeExtension {
 }
 
 impl<U: Sized + PartialOrd> RangeExtension for RangeInclusive<U> {
+    /// Empty ranges are in any other.
     fn contains_range(&self, other: &Self) -> bool {
-        self.contains(other.start()) && self.contains(other.end())
+        other.is_empty() || (self.contains(other.start()) && self.contains(other.end()))
     }
 
+    /// Empty ranges overlap nothing.
     fn overlaps(&self, other: &Self) -> bool {
-        self.contains(other.start()) || other.contains(self.start())
+        self.contains(other.start()) && !other.is_empty()
+            || other.contains(self.start()) && !self.is_empty()
+    }
+}
+
+/// Sets of integers, as the few ranges they make up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // Sorted, neither empty nor overlapping nor next to each other.
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many integers are in the set, which must fit a `usize`.
+    pub fn len(&self) -> usize {
+        let len: i128 = self
+            .ranges
+            .iter()
+            .map(|range| range.end().wide_offset_from(*range.start()) + 1)
+            .sum();
+        usize::try_from(len).expect("Too many integers to count.")
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|range| *range.end() < value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        *self = self.union(&IntervalSet::from(range));
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        *self = self.difference(&IntervalSet::from(range));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::merged(self.ranges().chain(other.ranges()).collect())
+    }
+
+    // The set of any non-empty ranges, whichever their order.
+    fn merged(mut ranges: Vec<RangeInclusive<T>>) -> Self {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_unstable_by_key(|range| *range.start());
+
+        // Merge each range into the previous one if they overlap or touch.
+        let mut merged: Vec<RangeInclusive<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start().wide_offset_from(*last.end()) <= 1 => {
+                    *last = *last.start()..=*last.end().max(range.end());
+                }
+                _ => merged.push(range),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let range = *x.start().max(y.start())..=*x.end().min(y.end());
+            if !range.is_empty() {
+                ranges.push(range);
+            }
+            // Whichever ends first cannot overlap anything else.
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(*first.start()..=*last.end()))
+            }
+            _ => IntervalSet::new(),
+        }
+    }
+
+    /// Everything in `within` that is not in the set.
+    pub fn complement(&self, within: RangeInclusive<T>) -> Self {
+        let (low, high) = within.into_inner();
+        let mut ranges = Vec::new();
+        // Where the next range missing from the set starts, if anywhere.
+        let mut start = Some(low);
+        for range in &self.ranges {
+            if let (Some(start), Some(end)) = (start, range.start().checked_offset(-1)) {
+                ranges.push(start..=end.min(high));
+            }
+            start = range.end().checked_offset(1).map(|next| next.max(low));
+        }
+        if let Some(start) = start {
+            ranges.push(start..=high);
+        }
+
+        ranges.retain(|range| !range.is_empty());
+        IntervalSet { ranges }
+    }
+
+    /// The ranges missing between the first and last values of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).map(|pair| {
+            let start = pair[0].end().checked_offset(1).expect("Apart.");
+            let end = pair[1].start().checked_offset(-1).expect("Apart.");
+            start..=end
+        })
+    }
+}
+
+impl<T: Coordinate> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Coordinate> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let ranges = Some(range).filter(|range| !range.is_empty());
+        IntervalSet {
+            ranges: ranges.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Coordinate> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        match range.end.checked_offset(-1) {
+            Some(end) => IntervalSet::from(range.start..=end),
+            None => IntervalSet::new(),
+        }
+    }
+}
+
+impl<T: Coordinate> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        Self::merged(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn empty_ranges() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 3..=1;
+        assert!(!(0..=5).overlaps(&empty));
+        assert!(!empty.overlaps(&(0..=5)));
+        assert!((0..=5).contains_range(&empty));
+        assert!((0..=5).overlaps(&(5..=7)));
+        assert!(!(0..=5).overlaps(&(6..=7)));
+        assert!(IntervalSet::from(empty).is_empty());
+        assert!(IntervalSet::from(4..4).is_empty());
+        assert!(IntervalSet::<u8>::from(0..0).is_empty());
+        assert_eq!(IntervalSet::from(2..5), IntervalSet::from(2..=4));
+    }
+
+    fn random_set(rng: &mut Rng) -> (IntervalSet<i32>, BTreeSet<i32>) {
+        let mut set = IntervalSet::new();
+        let mut cells = BTreeSet::new();
+        for _ in 0..rng.range(0..=5) {
+            let (a, b) = (rng.range(-15..=15), rng.range(-15..=15));
+            if rng.chance(0.7) {
+                set.insert(a..=b);
+                cells.extend(a..=b);
+            } else {
+                set.remove(a..=b);
+                cells.retain(|x| !(a..=b).contains(x));
+            }
+        }
+        (set, cells)
+    }
+
+    fn cells(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+        set.ranges().flatten().collect()
+    }
+
+    #[test]
+    fn sets_match_cells() {
+        let mut rng = Rng::new(23);
+        for _ in 0..500 {
+            let (a, a_cells) = random_set(&mut rng);
+            let (b, b_cells) = random_set(&mut rng);
+
+            // Ranges stay sorted and apart.
+            for set in [&a, &b] {
+                assert!(set
+                    .ranges
+                    .windows(2)
+                    .all(|w| w[0].end() + 1 < *w[1].start()));
+            }
+            assert_eq!(cells(&a), a_cells);
+            assert_eq!(a.len(), a_cells.len());
+            assert!((-20..=20).all(|x| a.contains(x) == a_cells.contains(&x)));
+
+            assert_eq!(cells(&a.union(&b)), &a_cells | &b_cells);
+            assert_eq!(cells(&a.intersection(&b)), &a_cells & &b_cells);
+            assert_eq!(cells(&a.difference(&b)), &a_cells - &b_cells);
+            let within: BTreeSet<i32> = (-10..=10).collect();
+            assert_eq!(cells(&a.complement(-10..=10)), &within - &a_cells);
+
+            let gaps: BTreeSet<i32> = a.gaps().flatten().collect();
+            let hull: BTreeSet<i32> = match (a_cells.first(), a_cells.last()) {
+                (Some(&first), Some(&last)) => (first..=last).collect(),
+                _ => BTreeSet::new(),
+            };
+            assert_eq!(gaps, &hull - &a_cells);
+        }
+    }
+
+    #[test]
+    fn merging() {
+        let set: IntervalSet<u8> = [5..=7, 0..=2, 3..=3, 9..=9, 8..=8].into_iter().collect();
+        assert_eq!(set.ranges().collect::<Vec<_>>(), [0..=3, 5..=9]);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [4..=4]);
+        assert_eq!(set.len(), 9);
+        assert_eq!(
+            set.complement(0..=255).ranges().collect::<Vec<_>>(),
+            [4..=4, 10..=255]
+        );
+    }
+
+    #[test]
+    fn wide_ranges() {
+        let set: IntervalSet<u64> = [0..=0, u64::MAX - 1..=u64::MAX].into_iter().collect();
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [1..=u64::MAX - 2]);
+
+        let set: IntervalSet<i64> = [i64::MIN..=0, 1..=i64::MAX / 2].into_iter().collect();
+        assert_eq!(set.ranges().collect::<Vec<_>>(), [i64::MIN..=i64::MAX / 2]);
+        assert_eq!(set.len(), usize::MAX - usize::MAX / 4);
     }
 }
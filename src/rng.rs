@@ -0,0 +1,77 @@
+//! A small seedable random number generator (SplitMix64), as the standard
+//! library has none. Good enough to generate inputs, not for anything secure.
+
+use std::ops::RangeInclusive;
+
+/// Integers that can be drawn from a range.
+pub trait Sample: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! sample {
+    ($($t:ty),*) => {
+        $(impl Sample for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Self {
+                value as $t
+            }
+        })*
+    };
+}
+
+sample!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` being positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Nothing below 0.");
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range<T: Sample>(&mut self, range: RangeInclusive<T>) -> T {
+        let (start, end) = (range.start().to_i128(), range.end().to_i128());
+        assert!(start <= end, "Empty range.");
+        let len = end - start + 1;
+        // Ranges of more than 2^64 numbers are only ever the full ones.
+        let offset = match u64::try_from(len) {
+            Ok(len) => i128::from(self.below(len)),
+            Err(_) => i128::from(self.next_u64()),
+        };
+        T::from_i128(start + offset)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx as u64 + 1) as usize);
+        }
+    }
+}
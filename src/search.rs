@@ -0,0 +1,262 @@
+//! Shortest paths through graphs that are only known through the neighbours
+//! of each state.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// How far the goal was, and the states leading to it, starts and goal
+/// included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Costs add up from nothing, their default.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = Self>> Cost for C {}
+
+/// The path with the fewest steps from any of `starts` to a state where
+/// `goal` holds.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    // Where each state was first reached from.
+    let mut parents = HashMap::new();
+    let mut to_visit = VecDeque::new();
+    for start in starts {
+        if parents.insert(start.clone(), None).is_none() {
+            to_visit.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, steps)) = to_visit.pop_front() {
+        if goal(&state) {
+            return Some(Path {
+                cost: steps,
+                states: backtrack(&parents, state),
+            });
+        }
+
+        for next in neighbours(&state) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(state.clone()));
+                to_visit.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// The fewest steps from any of `starts` to every state that can be reached.
+pub fn distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut to_visit = VecDeque::new();
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            to_visit.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, steps)) = to_visit.pop_front() {
+        for next in neighbours(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps + 1);
+                to_visit.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// The cheapest path from any of `starts` to a state where `goal` holds,
+/// `neighbours` telling what each step costs.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), goal)
+}
+
+/// As `dijkstra`, looking first where `heuristic` expects the goal to be the
+/// closest. It must never overestimate what is left to pay.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // The cheapest way found to each state, and where it came from.
+    let mut costs: HashMap<S, C> = HashMap::new();
+    let mut parents = HashMap::new();
+    // States need not be ordered: the queue refers to them by index.
+    let mut states = Vec::new();
+    let mut to_visit = BinaryHeap::new();
+    for start in starts {
+        if costs.insert(start.clone(), C::default()).is_none() {
+            parents.insert(start.clone(), None);
+            to_visit.push(Reverse((heuristic(&start), C::default(), states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, idx))) = to_visit.pop() {
+        let state = states[idx].clone();
+        // A cheaper way was found since.
+        if costs[&state] < cost {
+            continue;
+        }
+        if goal(&state) {
+            return Some(Path {
+                cost,
+                states: backtrack(&parents, state),
+            });
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), Some(state.clone()));
+                to_visit.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+// The states leading to `state`, from the start it was reached from.
+fn backtrack<S: Clone + Eq + Hash>(parents: &HashMap<S, Option<S>>, state: S) -> Vec<S> {
+    let mut path = vec![state];
+    while let Some(Some(parent)) = parents.get(path.last().expect("Not empty.")) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    const MAZE: &str = "\
+S.#.....
+..#.##.#
+.##..#..
+....#..G
+";
+
+    fn maze() -> (Grid<char>, Position, Position) {
+        let maze = MAZE.parse::<Grid<char>>().unwrap();
+        let find = |c| maze.iter().find(|&(_, &t)| t == c).unwrap().0;
+        let (start, goal) = (find('S'), find('G'));
+        (maze, start, goal)
+    }
+
+    // Each path must only take single steps through open squares.
+    fn check(maze: &Grid<char>, path: &[Position]) {
+        for pair in path.windows(2) {
+            assert_eq!(pair[0].manhattan_distance(pair[1]), 1, "{path:?}");
+            assert_ne!(maze[pair[1]], '#', "{path:?}");
+        }
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let (maze, start, goal) = maze();
+        let open = |p: &Position| {
+            let p = *p;
+            maze.neighbours4(p).filter(|&n| maze[n] != '#')
+        };
+
+        let path = bfs([start], open, |&p| p == goal).unwrap();
+        assert_eq!(path.cost, 16);
+        assert_eq!(path.states.len(), 17);
+        assert_eq!((path.states[0], path.states[16]), (start, goal));
+        check(&maze, &path.states);
+        assert_eq!(distances([start], open)[&goal], 16);
+
+        let weighted = |p: &Position| open(p).map(|n| (n, 1)).collect::<Vec<_>>();
+        let path = dijkstra([start], weighted, |&p| p == goal).unwrap();
+        assert_eq!(path.cost, 16);
+        check(&maze, &path.states);
+        let path = astar(
+            [start],
+            weighted,
+            |&p| p.manhattan_distance(goal),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 16);
+        check(&maze, &path.states);
+
+        // The nearest of several starts.
+        let starts = [start, Position::new(3, 0)];
+        let path = bfs(starts, open, |&p| p == goal).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states[0], Position::new(3, 0));
+
+        assert_eq!(bfs([start], open, |&p| p == Position::new(2, 0)), None);
+    }
+
+    #[test]
+    fn costs() {
+        // Going the long way around is cheaper than the direct edge.
+        let edges = |&n: &u8| match n {
+            0 => vec![(1, 10), (2, 1)],
+            2 => vec![(3, 1)],
+            3 => vec![(1, 1)],
+            _ => vec![],
+        };
+        let path = dijkstra([0], edges, |&n| n == 1).unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 3,
+                states: vec![0, 2, 3, 1]
+            }
+        );
+        assert_eq!(
+            bfs([0], |&n| edges(&n).into_iter().map(|(n, _)| n), |&n| n == 1)
+                .unwrap()
+                .cost,
+            1
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::parse::ParseError;
+use std::any::Any;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Parse the value of the parameter `name`.
+pub fn parameter<T: FromStr>(name: &str, value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {name}: {value}.").into())
+}
+
+pub fn unknown_parameter(name: &str) -> Error {
+    format!("Unknown parameter: {name}.").into()
+}
+
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    const DAY: u8;
+
+    // There is no second part on Christmas day.
+    const PARTS: u8 = 2;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2>;
+
+    /// The puzzle's constants (such as row 2000000 on day 15), by name, with
+    /// their current values.
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<()> {
+        Err(unknown_parameter(name))
+    }
+}
+
+/// A `Solution` with its types erased, so that every day can be registered
+/// (and run) the same way.
+pub trait DynSolution {
+    fn parts(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<String>;
+    fn parameters(&self) -> Vec<(&'static str, String)>;
+    fn set(&mut self, name: &str, value: &str) -> Result<()>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        match Solution::parse(self, input) {
+            Ok(input) => Ok(Box::new(input)),
+            // Parsers don't know which day they belong to.
+            Err(e) => match e.downcast::<ParseError>() {
+                Ok(e) => Err(Box::new(e.with_day(S::DAY))),
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("Input parsed by another solution.")?;
+
+        match part {
+            1 => Ok(self.part1(input)?.to_string()),
+            2 if S::PARTS >= 2 => Ok(self.part2(input)?.to_string()),
+            _ => Err(format!("There is no part {part}.").into()),
+        }
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        Solution::parameters(self)
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        Solution::set(self, name, value)
+    }
+}
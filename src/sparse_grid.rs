@@ -0,0 +1,243 @@
+//! Unbounded grids where only a few cells hold anything, such as sand piling
+//! up or elves spreading out.
+
+use crate::position::{Coordinate, Direction, Orientation, Position, Screen};
+use std::collections::btree_map::{BTreeMap, Entry};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T, C = isize, O = Screen> {
+    cells: HashMap<Position<C, O>, T>,
+    // How many cells each column and row holds, so that the bounds are their
+    // first and last ones, however cells come and go.
+    columns: BTreeMap<C, usize>,
+    rows: BTreeMap<C, usize>,
+}
+
+impl<T, C: Coordinate, O: Orientation> SparseGrid<T, C, O> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            columns: BTreeMap::new(),
+            rows: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: Position<C, O>) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn get(&self, position: Position<C, O>) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: Position<C, O>) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    /// Fill a cell, returning what it held before.
+    pub fn insert(&mut self, position: Position<C, O>, value: T) -> Option<T> {
+        let previous = self.cells.insert(position, value);
+        if previous.is_none() {
+            *self.columns.entry(position.x()).or_default() += 1;
+            *self.rows.entry(position.y()).or_default() += 1;
+        }
+        previous
+    }
+
+    /// Empty a cell, returning what it held. The bounds shrink if it was the
+    /// last one on their edge.
+    pub fn remove(&mut self, position: Position<C, O>) -> Option<T> {
+        let value = self.cells.remove(&position)?;
+        leave(&mut self.columns, position.x());
+        leave(&mut self.rows, position.y());
+        Some(value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position<C, O>, &T)> {
+        self.cells.iter().map(|(&p, v)| (p, v))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position<C, O>> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// The smallest and largest coordinates of any cell, unless there are none.
+    pub fn bounds(&self) -> Option<(Position<C, O>, Position<C, O>)> {
+        let (&left, &right) = (
+            self.columns.keys().next()?,
+            self.columns.keys().next_back()?,
+        );
+        let (&low, &high) = (self.rows.keys().next()?, self.rows.keys().next_back()?);
+        Some((Position::new(left, low), Position::new(right, high)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| max.x().offset_from(min.x()) as usize + 1)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| max.y().offset_from(min.y()) as usize + 1)
+    }
+
+    /// How many cells of the bounding rectangle are empty.
+    pub fn empty_cells(&self) -> usize {
+        self.width() * self.height() - self.len()
+    }
+
+    /// Every cell of the bounding rectangle, row by row from the top.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&T>>> {
+        let top_left = self.bounds().map(|(min, max)| {
+            let top = if O::UP < 0 { min.y() } else { max.y() };
+            Position::new(min.x(), top)
+        });
+        let (width, height) = (self.width(), self.height());
+
+        top_left.into_iter().flat_map(move |top_left| {
+            (0..height).map(move |idy| {
+                let start = step(top_left, Direction::DOWN, idy);
+                (0..width).map(move |idx| self.get(step(start, Direction::RIGHT, idx)))
+            })
+        })
+    }
+
+    /// Print the bounding rectangle one row per line, turning each cell into
+    /// a character with `cell`.
+    pub fn render<F: Fn(Option<&T>) -> char>(&self, cell: F) -> Render<'_, T, C, O, F> {
+        Render { grid: self, cell }
+    }
+}
+
+// One cell fewer in a column or row, which is forgotten once empty.
+fn leave<C: Ord>(counts: &mut BTreeMap<C, usize>, key: C) {
+    if let Entry::Occupied(mut count) = counts.entry(key) {
+        *count.get_mut() -= 1;
+        if *count.get() == 0 {
+            count.remove();
+        }
+    }
+}
+
+// Cells inside the bounds always fit.
+fn step<C: Coordinate, O: Orientation>(
+    position: Position<C, O>,
+    direction: Direction,
+    count: usize,
+) -> Position<C, O> {
+    let (dx, dy) = (direction.dx(), direction.dy());
+    position
+        .checked_add(Direction::new(dx * count as isize, dy * count as isize))
+        .expect("Within the bounds.")
+}
+
+impl<T, C: Coordinate, O: Orientation> Default for SparseGrid<T, C, O> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T, C: Coordinate, O: Orientation> Extend<(Position<C, O>, T)> for SparseGrid<T, C, O> {
+    fn extend<I: IntoIterator<Item = (Position<C, O>, T)>>(&mut self, iter: I) {
+        for (position, value) in iter {
+            self.insert(position, value);
+        }
+    }
+}
+
+impl<T, C: Coordinate, O: Orientation> FromIterator<(Position<C, O>, T)> for SparseGrid<T, C, O> {
+    fn from_iter<I: IntoIterator<Item = (Position<C, O>, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+pub struct Render<'a, T, C, O, F> {
+    grid: &'a SparseGrid<T, C, O>,
+    cell: F,
+}
+
+impl<T, C: Coordinate, O: Orientation, F: Fn(Option<&T>) -> char> Display
+    for Render<'_, T, C, O, F>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.grid.rows() {
+            let line: String = row.map(&self.cell).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::Math;
+
+    #[test]
+    fn bounds() {
+        let mut grid: SparseGrid<char> = SparseGrid::new();
+        assert_eq!((grid.width(), grid.height(), grid.empty_cells()), (0, 0, 0));
+        assert_eq!(grid.render(|_| '.').to_string(), "");
+
+        grid.extend([(Position::new(-1, 2), 'a'), (Position::new(2, 0), 'b')]);
+        grid.insert(Position::new(0, 1), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some((Position::new(-1, 0), Position::new(2, 2)))
+        );
+        assert_eq!((grid.width(), grid.height(), grid.empty_cells()), (4, 3, 9));
+        assert_eq!(
+            grid.render(|c| c.copied().unwrap_or('.')).to_string(),
+            "...b\n.c..\na...\n"
+        );
+
+        assert_eq!(grid.remove(Position::new(2, 0)), Some('b'));
+        assert_eq!(grid.remove(Position::new(2, 0)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((Position::new(-1, 1), Position::new(0, 2)))
+        );
+        assert_eq!(grid.empty_cells(), 2);
+
+        // Bounds only shrink as far as the cells left allow.
+        grid.insert(Position::new(0, 1), 'd');
+        grid.insert(Position::new(0, 2), 'e');
+        assert_eq!(grid.remove(Position::new(0, 1)), Some('d'));
+        assert_eq!(
+            grid.bounds(),
+            Some((Position::new(-1, 2), Position::new(0, 2)))
+        );
+        grid.remove(Position::new(-1, 2));
+        grid.remove(Position::new(0, 2));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn orientation() {
+        let grid: SparseGrid<(), usize, Math> =
+            [(Position::new(3, 5), ()), (Position::new(4, 6), ())]
+                .into_iter()
+                .collect();
+        let rows: Vec<Vec<bool>> = grid
+            .rows()
+            .map(|row| row.map(|cell| cell.is_some()).collect())
+            .collect();
+        assert_eq!(rows, [[false, true], [true, false]]);
+    }
+}